@@ -0,0 +1,149 @@
+/// Create a function that applies `f`, then `g`.
+///
+/// `compose(f, g)` is equivalent to `move |x| g(f(x))`.
+///
+/// The returned function implements [`FnOnce`]. Use [`compose_fn`] or [`compose_fn_mut`]
+/// to create a function that can be called more than once.
+///
+/// ```
+/// # use pipe_trait::*;
+/// let parse = |text: &str| text.parse::<i32>().unwrap();
+/// let validate = |x: i32| x.max(0);
+/// assert_eq!("12".pipe(compose(parse, validate)), 12);
+/// assert_eq!("-12".pipe(compose(parse, validate)), 0);
+/// ```
+#[inline]
+pub fn compose<Param, Middle, Return, First, Second>(
+    f: First,
+    g: Second,
+) -> impl FnOnce(Param) -> Return
+where
+    First: FnOnce(Param) -> Middle,
+    Second: FnOnce(Middle) -> Return,
+{
+    move |x| g(f(x))
+}
+
+/// Create a function that applies `f`, then `g`, and implements [`Fn`].
+///
+/// ```
+/// # use pipe_trait::*;
+/// let describe = compose_fn(|x: i32| x * 2, |x: i32| x.to_string());
+/// assert_eq!(describe(3), "6");
+/// assert_eq!(describe(4), "8");
+/// ```
+#[inline]
+pub fn compose_fn<Param, Middle, Return, First, Second>(
+    f: First,
+    g: Second,
+) -> impl Fn(Param) -> Return
+where
+    First: Fn(Param) -> Middle,
+    Second: Fn(Middle) -> Return,
+{
+    move |x| g(f(x))
+}
+
+/// Create a function that applies `f`, then `g`, and implements [`FnMut`].
+///
+/// ```
+/// # use pipe_trait::*;
+/// let mut total = 0;
+/// let mut accumulate = compose_fn_mut(|x: i32| x * 2, |x: i32| {
+///     total += x;
+///     total
+/// });
+/// assert_eq!(accumulate(3), 6);
+/// assert_eq!(accumulate(4), 14);
+/// ```
+#[inline]
+pub fn compose_fn_mut<Param, Middle, Return, First, Second>(
+    mut f: First,
+    mut g: Second,
+) -> impl FnMut(Param) -> Return
+where
+    First: FnMut(Param) -> Middle,
+    Second: FnMut(Middle) -> Return,
+{
+    move |x| g(f(x))
+}
+
+/// Create a function that applies `f`, then `g`, then `h`.
+///
+/// `compose3(f, g, h)` is equivalent to `move |x| h(g(f(x)))`.
+///
+/// The returned function implements [`FnOnce`]. Use [`compose3_fn`] or [`compose3_fn_mut`]
+/// to create a function that can be called more than once.
+///
+/// ```
+/// # use pipe_trait::*;
+/// let normalize = compose3(str::trim, str::to_lowercase, |x: String| x + "!");
+/// assert_eq!("  Hello ".pipe(normalize), "hello!");
+/// ```
+#[inline]
+pub fn compose3<Param, FirstOutput, SecondOutput, Return, First, Second, Third>(
+    f: First,
+    g: Second,
+    h: Third,
+) -> impl FnOnce(Param) -> Return
+where
+    First: FnOnce(Param) -> FirstOutput,
+    Second: FnOnce(FirstOutput) -> SecondOutput,
+    Third: FnOnce(SecondOutput) -> Return,
+{
+    move |x| h(g(f(x)))
+}
+
+/// Create a function that applies `f`, then `g`, then `h`, and implements [`Fn`].
+///
+/// ```
+/// # use pipe_trait::*;
+/// let normalize = compose3_fn(str::trim, str::to_lowercase, |x: String| x + "!");
+/// assert_eq!(normalize("  Hello "), "hello!");
+/// assert_eq!(normalize(" World  "), "world!");
+/// ```
+#[inline]
+pub fn compose3_fn<Param, FirstOutput, SecondOutput, Return, First, Second, Third>(
+    f: First,
+    g: Second,
+    h: Third,
+) -> impl Fn(Param) -> Return
+where
+    First: Fn(Param) -> FirstOutput,
+    Second: Fn(FirstOutput) -> SecondOutput,
+    Third: Fn(SecondOutput) -> Return,
+{
+    move |x| h(g(f(x)))
+}
+
+/// Create a function that applies `f`, then `g`, then `h`, and implements [`FnMut`].
+///
+/// ```
+/// # use pipe_trait::*;
+/// let mut calls = 0;
+/// let mut count = compose3_fn_mut(
+///     |x: i32| x + 1,
+///     |x: i32| x * 2,
+///     |x: i32| {
+///         calls += 1;
+///         x
+///     },
+/// );
+/// assert_eq!(count(1), 4);
+/// assert_eq!(count(2), 6);
+/// drop(count);
+/// assert_eq!(calls, 2);
+/// ```
+#[inline]
+pub fn compose3_fn_mut<Param, FirstOutput, SecondOutput, Return, First, Second, Third>(
+    mut f: First,
+    mut g: Second,
+    mut h: Third,
+) -> impl FnMut(Param) -> Return
+where
+    First: FnMut(Param) -> FirstOutput,
+    Second: FnMut(FirstOutput) -> SecondOutput,
+    Third: FnMut(SecondOutput) -> Return,
+{
+    move |x| h(g(f(x)))
+}
@@ -0,0 +1,154 @@
+//! Futures returned by the async methods of this crate.
+//!
+//! This module requires the `async` feature or the `tokio` feature.
+#[cfg(feature = "async")]
+use crate::Pipe;
+#[cfg(feature = "async")]
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+#[cfg(feature = "async")]
+use pin_project_lite::pin_project;
+
+#[cfg(all(feature = "async", feature = "alloc"))]
+use alloc::boxed::Box;
+
+#[cfg(feature = "tokio")]
+pub use tokio::{
+    task::{JoinError, JoinHandle},
+    time::{error::Elapsed, Timeout},
+};
+
+/// Boxed future returned by [`AsyncPipeline::apply`](crate::AsyncPipeline::apply).
+///
+/// This type requires the `async` feature and the `alloc` feature.
+#[cfg(all(feature = "async", feature = "alloc"))]
+pub type BoxFuture<'a, Output> = Pin<Box<dyn Future<Output = Output> + Send + 'a>>;
+
+/// Boxed future returned by [`Pipe::tap_async`](crate::Pipe::tap_async).
+///
+/// This type requires the `async` feature and the `alloc` feature.
+#[cfg(all(feature = "async", feature = "alloc"))]
+pub type LocalBoxFuture<'a, Output> = Pin<Box<dyn Future<Output = Output> + 'a>>;
+
+/// Async callback whose future may borrow its argument, accepted by [`Pipe::tap_async`](crate::Pipe::tap_async).
+///
+/// This trait is implemented for every `FnOnce(&'a Value) -> Fut`, so an `async fn` taking `&Value` qualifies.
+#[cfg(feature = "async")]
+pub trait TapAsyncFn<'a, Value: ?Sized + 'a> {
+    /// Future returned by the callback.
+    type Future: Future<Output = ()>;
+
+    /// Call the callback with a borrowed `value`.
+    fn call(self, value: &'a Value) -> Self::Future;
+}
+
+#[cfg(feature = "async")]
+impl<'a, Value, Fut, Function> TapAsyncFn<'a, Value> for Function
+where
+    Value: ?Sized + 'a,
+    Function: FnOnce(&'a Value) -> Fut,
+    Fut: Future<Output = ()>,
+{
+    type Future = Fut;
+
+    #[inline]
+    fn call(self, value: &'a Value) -> Self::Future {
+        self(value)
+    }
+}
+
+#[cfg(feature = "async")]
+pin_project! {
+    /// Future returned by [`PipeResult::pipe_ok_async`](crate::PipeResult::pipe_ok_async).
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct PipeOkAsync<Fut, Error> {
+        #[pin]
+        future: Option<Fut>,
+        error: Option<Error>,
+    }
+}
+
+#[cfg(feature = "async")]
+impl<Fut, Error> PipeOkAsync<Fut, Error> {
+    pub(crate) fn ok(future: Fut) -> Self {
+        PipeOkAsync {
+            future: Some(future),
+            error: None,
+        }
+    }
+
+    pub(crate) fn err(error: Error) -> Self {
+        PipeOkAsync {
+            future: None,
+            error: Some(error),
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+impl<Fut, Error> Future for PipeOkAsync<Fut, Error>
+where
+    Fut: Future,
+{
+    type Output = Result<Fut::Output, Error>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        match this.future.as_pin_mut() {
+            Some(future) => future.poll(cx).map(Ok),
+            None => this
+                .error
+                .take()
+                .expect("PipeOkAsync must not be polled after completion")
+                .pipe(Err)
+                .pipe(Poll::Ready),
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+pin_project! {
+    /// Future returned by [`Pipe::pipe_join_await`](crate::Pipe::pipe_join_await).
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct PipeJoinAwait<Value, Fut, Function> {
+        state: Option<(Value, Function)>,
+        #[pin]
+        future: Fut,
+    }
+}
+
+#[cfg(feature = "async")]
+impl<Value, Fut, Function> PipeJoinAwait<Value, Fut, Function> {
+    pub(crate) fn new(value: Value, future: Fut, f: Function) -> Self {
+        PipeJoinAwait {
+            state: Some((value, f)),
+            future,
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+impl<Value, Fut, Function, Return> Future for PipeJoinAwait<Value, Fut, Function>
+where
+    Fut: Future,
+    Function: FnOnce((Value, Fut::Output)) -> Return,
+{
+    type Output = Return;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        match this.future.poll(cx) {
+            Poll::Ready(output) => {
+                let (value, f) = this
+                    .state
+                    .take()
+                    .expect("PipeJoinAwait must not be polled after completion");
+                f((value, output)).pipe(Poll::Ready)
+            }
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
@@ -1,10 +1,54 @@
 #![doc = include_str!("doc.md")]
 #![no_std]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "alloc")]
+use alloc::{
+    boxed::Box,
+    string::{String, ToString},
+    vec::Vec,
+};
+#[cfg(feature = "alloc")]
+use core::fmt::Display;
 use core::{
     borrow::{Borrow, BorrowMut},
+    convert::{TryFrom, TryInto},
     ops::{Deref, DerefMut},
+    str::FromStr,
 };
 
+#[cfg(any(feature = "async", feature = "tokio", feature = "futures"))]
+use core::future::Future;
+#[cfg(feature = "tokio")]
+use core::time::Duration;
+
+#[cfg(any(feature = "async", feature = "tokio"))]
+pub mod future;
+
+mod compose;
+mod macros;
+mod nested;
+mod option;
+mod partial;
+#[cfg(feature = "alloc")]
+mod pipeline;
+mod result;
+#[cfg(feature = "futures")]
+mod stream;
+pub use compose::{compose, compose3, compose3_fn, compose3_fn_mut, compose_fn, compose_fn_mut};
+pub use nested::{PipeFlattenResult, PipeTranspose};
+pub use option::PipeOption;
+pub use partial::{curry, curry_right, partial_right};
+#[cfg(all(feature = "alloc", feature = "async"))]
+pub use pipeline::AsyncPipeline;
+#[cfg(feature = "alloc")]
+pub use pipeline::{Pipeline, ResultPipeline};
+pub use result::PipeResult;
+#[cfg(feature = "futures")]
+pub use stream::PipeStream;
+
 /// All sized types implement this trait.
 pub trait Pipe {
     /// Apply `f` to `self`.
@@ -79,6 +123,16 @@ pub trait Pipe {
     /// let y: String = x.pipe_as_ref(uppercase);
     /// assert_eq!(y, "ABC");
     /// ```
+    ///
+    /// If `Self` implements [`AsRef`] for several types, `Param` can be chosen with a turbofish:
+    ///
+    /// ```
+    /// # use pipe_trait::*;
+    /// use std::{ffi::OsStr, path::Path};
+    /// let x: String = "src/lib.rs".to_string();
+    /// let y = x.pipe_as_ref::<Path, _, _>(Path::extension);
+    /// assert_eq!(y, Some(OsStr::new("rs")));
+    /// ```
     #[inline]
     fn pipe_as_ref<'a, Param, Return, Function>(&'a self, f: Function) -> Return
     where
@@ -101,6 +155,15 @@ pub trait Pipe {
     /// vec.pipe_as_mut(modify);
     /// assert_eq!(vec, vec![123, 1, 2, 3]);
     /// ```
+    ///
+    /// If `Self` implements [`AsMut`] for several types, `Param` can be chosen with a turbofish:
+    ///
+    /// ```
+    /// # use pipe_trait::*;
+    /// let mut vec: Vec<i32> = vec![3, 1, 2];
+    /// vec.pipe_as_mut::<[i32], _, _>(<[i32]>::sort_unstable);
+    /// assert_eq!(vec, vec![1, 2, 3]);
+    /// ```
     #[inline]
     fn pipe_as_mut<'a, Param, Return, Function>(&'a mut self, f: Function) -> Return
     where
@@ -198,6 +261,1607 @@ pub trait Pipe {
     {
         f(self.borrow_mut())
     }
+
+    /// Apply `f` to `self` if `condition` is `true`, otherwise return `self` unchanged.
+    ///
+    /// Since `f` must return `Self`, only transformations that preserve the type are accepted.
+    /// `f` is not called when `condition` is `false`.
+    ///
+    /// ```
+    /// # use pipe_trait::*;
+    /// fn uppercase(x: String) -> String {
+    ///   x.to_uppercase()
+    /// }
+    /// assert_eq!("abc".to_string().pipe_if(true, uppercase), "ABC");
+    /// assert_eq!("abc".to_string().pipe_if(false, uppercase), "abc");
+    /// ```
+    #[inline]
+    fn pipe_if<Function>(self, condition: bool, f: Function) -> Self
+    where
+        Self: Sized,
+        Function: FnOnce(Self) -> Self,
+    {
+        if condition {
+            f(self)
+        } else {
+            self
+        }
+    }
+
+    /// Apply `f` to `self` if `condition` is `false`, otherwise return `self` unchanged.
+    ///
+    /// This is the complement of [`Pipe::pipe_if`].
+    /// `f` is not called when `condition` is `true`.
+    ///
+    /// ```
+    /// # use pipe_trait::*;
+    /// fn uppercase(x: String) -> String {
+    ///   x.to_uppercase()
+    /// }
+    /// assert_eq!("abc".to_string().pipe_unless(false, uppercase), "ABC");
+    /// assert_eq!("abc".to_string().pipe_unless(true, uppercase), "abc");
+    /// ```
+    #[inline]
+    fn pipe_unless<Function>(self, condition: bool, f: Function) -> Self
+    where
+        Self: Sized,
+        Function: FnOnce(Self) -> Self,
+    {
+        if condition {
+            self
+        } else {
+            f(self)
+        }
+    }
+
+    /// Apply `if_true` to `self` if `condition` is `true`, otherwise apply `if_false`.
+    ///
+    /// Unlike [`Pipe::pipe_if`], the type may change as long as both functions return the same type.
+    /// Only the selected function is called.
+    ///
+    /// ```
+    /// # use pipe_trait::*;
+    /// let format_long = |x: i32| format!("value: {}", x);
+    /// let format_short = |x: i32| x.to_string();
+    /// assert_eq!(12.pipe_if_else(true, format_long, format_short), "value: 12");
+    /// assert_eq!(12.pipe_if_else(false, format_long, format_short), "12");
+    /// ```
+    #[inline]
+    fn pipe_if_else<Return, IfTrue, IfFalse>(
+        self,
+        condition: bool,
+        if_true: IfTrue,
+        if_false: IfFalse,
+    ) -> Return
+    where
+        Self: Sized,
+        IfTrue: FnOnce(Self) -> Return,
+        IfFalse: FnOnce(Self) -> Return,
+    {
+        if condition {
+            if_true(self)
+        } else {
+            if_false(self)
+        }
+    }
+
+    /// Apply `f` to `self` if `predicate` returns `true` for `&self`, otherwise return `self` unchanged.
+    ///
+    /// `predicate` is called before `f`, and its borrow ends before `self` is moved into `f`.
+    ///
+    /// ```
+    /// # use pipe_trait::*;
+    /// fn truncate(mut x: String) -> String {
+    ///   x.truncate(3);
+    ///   x
+    /// }
+    /// let short = "abc".to_string().pipe_when(|x| x.len() > 3, truncate);
+    /// let long = "abcdef".to_string().pipe_when(|x| x.len() > 3, truncate);
+    /// assert_eq!(short, "abc");
+    /// assert_eq!(long, "abc");
+    /// ```
+    #[inline]
+    fn pipe_when<Predicate, Function>(self, predicate: Predicate, f: Function) -> Self
+    where
+        Self: Sized,
+        Predicate: FnOnce(&Self) -> bool,
+        Function: FnOnce(Self) -> Self,
+    {
+        if predicate(&self) {
+            f(self)
+        } else {
+            self
+        }
+    }
+
+    /// Apply `f` to `&self` if `condition` is `true`, wrapping the result in [`Some`],
+    /// otherwise return [`None`].
+    ///
+    /// ```
+    /// # use pipe_trait::*;
+    /// let x = "abc".to_string();
+    /// assert_eq!(x.pipe_ref_if(true, |x| x.len()), Some(3));
+    /// assert_eq!(x.pipe_ref_if(false, |x| x.len()), None);
+    /// assert_eq!(x, "abc"); // x is not moved
+    /// ```
+    #[inline]
+    fn pipe_ref_if<'a, Return, Function>(&'a self, condition: bool, f: Function) -> Option<Return>
+    where
+        Function: FnOnce(&'a Self) -> Return,
+    {
+        if condition {
+            Some(f(self))
+        } else {
+            None
+        }
+    }
+
+    /// Apply `f` to `&mut self` if `condition` is `true`, then return `&mut self`.
+    ///
+    /// ```
+    /// # use pipe_trait::*;
+    /// #[derive(Debug, PartialEq, Eq)]
+    /// struct Config {
+    ///     tls: bool,
+    ///     auth: bool,
+    /// }
+    /// let mut config = Config { tls: false, auth: false };
+    /// config
+    ///     .pipe_mut_if(true, |config| config.tls = true)
+    ///     .pipe_mut_if(false, |config| config.auth = true);
+    /// assert_eq!(config, Config { tls: true, auth: false });
+    /// ```
+    #[inline]
+    fn pipe_mut_if<Function>(&mut self, condition: bool, f: Function) -> &mut Self
+    where
+        Function: FnOnce(&mut Self),
+    {
+        if condition {
+            f(self);
+        }
+        self
+    }
+
+    /// Apply `f` to `&self` where `f` takes a single parameter of type `Param`
+    /// and `Self` implements trait [`AsRef<Param>`] if `condition` is `true`,
+    /// wrapping the result in [`Some`], otherwise return [`None`].
+    ///
+    /// `as_ref` is not called when `condition` is `false`.
+    ///
+    /// If `Self` implements [`AsRef`] for multiple types, `Param` can be specified with a turbofish:
+    ///
+    /// ```
+    /// # use pipe_trait::*;
+    /// fn uppercase(x: &str) -> String {
+    ///   x.to_uppercase()
+    /// }
+    /// let x: String = "abc".to_string();
+    /// let y = x.pipe_as_ref_if::<str, _, _>(true, uppercase);
+    /// let z = x.pipe_as_ref_if::<str, _, _>(false, uppercase);
+    /// assert_eq!(y.as_deref(), Some("ABC"));
+    /// assert_eq!(z, None);
+    /// ```
+    #[inline]
+    fn pipe_as_ref_if<'a, Param, Return, Function>(
+        &'a self,
+        condition: bool,
+        f: Function,
+    ) -> Option<Return>
+    where
+        Self: AsRef<Param>,
+        Param: ?Sized + 'a,
+        Function: FnOnce(&'a Param) -> Return,
+    {
+        if condition {
+            Some(f(self.as_ref()))
+        } else {
+            None
+        }
+    }
+
+    /// Apply `f` to `self` and the value inside `option` if `option` is [`Some`],
+    /// otherwise return `self` unchanged.
+    ///
+    /// ```
+    /// # use pipe_trait::*;
+    /// fn append(x: String, suffix: &str) -> String {
+    ///   x + suffix
+    /// }
+    /// assert_eq!("abc".to_string().pipe_if_some(Some("def"), append), "abcdef");
+    /// assert_eq!("abc".to_string().pipe_if_some(None, append), "abc");
+    /// ```
+    #[inline]
+    fn pipe_if_some<Value, Function>(self, option: Option<Value>, f: Function) -> Self
+    where
+        Self: Sized,
+        Function: FnOnce(Self, Value) -> Self,
+    {
+        match option {
+            Some(value) => f(self, value),
+            None => self,
+        }
+    }
+
+    /// Call `f` with `&self` for its side effect, then return `self`.
+    ///
+    /// ```
+    /// # use pipe_trait::*;
+    /// let mut log = Vec::new();
+    /// let x = 12
+    ///     .pipe(|x| x + 1)
+    ///     .tap(|x| log.push(*x))
+    ///     .pipe(|x| x * 2);
+    /// assert_eq!(x, 26);
+    /// assert_eq!(log, [13]);
+    /// ```
+    ///
+    /// `f` only receives a shared reference, so it cannot modify `self`:
+    ///
+    /// ```compile_fail
+    /// # use pipe_trait::*;
+    /// let x = vec![0, 1, 2].tap(|x| x.push(3));
+    /// ```
+    #[inline]
+    fn tap<Function>(self, f: Function) -> Self
+    where
+        Self: Sized,
+        Function: FnOnce(&Self),
+    {
+        f(&self);
+        self
+    }
+
+    /// Call `f` with `&mut self`, then return the modified `self`.
+    ///
+    /// Unlike [`Pipe::pipe_mut`], this method takes and returns `self` by value.
+    ///
+    /// ```
+    /// # use pipe_trait::*;
+    /// let vec = Vec::new()
+    ///     .tap_mut(|x| x.push(1))
+    ///     .tap_mut(|x| x.push(2));
+    /// assert_eq!(vec, [1, 2]);
+    /// ```
+    #[inline]
+    fn tap_mut<Function>(mut self, f: Function) -> Self
+    where
+        Self: Sized,
+        Function: FnOnce(&mut Self),
+    {
+        f(&mut self);
+        self
+    }
+
+    /// Call `f` with `&self` for its side effect, then return `&self`.
+    ///
+    /// ```
+    /// # use pipe_trait::*;
+    /// let mut log = Vec::new();
+    /// let len = "abc"
+    ///     .to_string()
+    ///     .tap_ref(|x| log.push(x.clone()))
+    ///     .pipe_ref(|x| x.len());
+    /// assert_eq!(len, 3);
+    /// assert_eq!(log, ["abc"]);
+    /// ```
+    #[inline]
+    fn tap_ref<Function>(&self, f: Function) -> &Self
+    where
+        Function: FnOnce(&Self),
+    {
+        f(self);
+        self
+    }
+
+    /// Call `f` with `&self` for its side effect if `debug_assertions` is enabled, then return `self`.
+    ///
+    /// In release builds (without `debug_assertions`), `f` is never called and this method is an
+    /// identity function. `f` is still type-checked, so code inside it cannot silently rot.
+    ///
+    /// ```
+    /// # use pipe_trait::*;
+    /// let mut log = Vec::new();
+    /// let x = 12
+    ///     .pipe(|x| x + 1)
+    ///     .tap_dbg(|x| log.push(*x))
+    ///     .pipe(|x| x * 2);
+    /// assert_eq!(x, 26);
+    /// // `log` is `[13]` if this crate is built with `debug_assertions`, and empty otherwise.
+    /// assert!(matches!(log.as_slice(), [13] | []));
+    /// ```
+    #[inline]
+    fn tap_dbg<Function>(self, f: Function) -> Self
+    where
+        Self: Sized,
+        Function: FnOnce(&Self),
+    {
+        if cfg!(debug_assertions) {
+            f(&self);
+        }
+        self
+    }
+
+    /// Call `f` with `&self` where `f` takes a single parameter of type `Param`
+    /// and `Self` implements trait `Deref<Target = Param>`, then return `&self`.
+    ///
+    /// ```
+    /// # use pipe_trait::*;
+    /// let x: String = "abc".to_string();
+    /// let len = x
+    ///     .tap_deref(|x: &str| assert_eq!(x, "abc"))
+    ///     .pipe_ref(|x| x.len());
+    /// assert_eq!(len, 3);
+    /// ```
+    #[inline]
+    fn tap_deref<Param, Function>(&self, f: Function) -> &Self
+    where
+        Self: Deref<Target = Param>,
+        Param: ?Sized,
+        Function: FnOnce(&Param),
+    {
+        f(self);
+        self
+    }
+
+    /// Call `f` with `&mut self` where `f` takes a single parameter of type `Param`
+    /// and `Self` implements trait [`DerefMut<Target = Param>`], then return `&mut self`.
+    ///
+    /// ```
+    /// # use pipe_trait::*;
+    /// let mut boxed: Box<[i32]> = Box::new([0, 1, 2, 3]);
+    /// boxed
+    ///     .tap_deref_mut(|x| x[0] = 9)
+    ///     .tap_deref_mut(|x| x.reverse());
+    /// assert_eq!(*boxed, [3, 2, 1, 9]);
+    /// ```
+    #[inline]
+    fn tap_deref_mut<Param, Function>(&mut self, f: Function) -> &mut Self
+    where
+        Self: DerefMut<Target = Param>,
+        Param: ?Sized,
+        Function: FnOnce(&mut Param),
+    {
+        f(self);
+        self
+    }
+
+    /// Call `f` with `&self` where `f` takes a single parameter of type `Param`
+    /// and `Self` implements trait [`AsRef<Param>`], then return `&self`.
+    ///
+    /// ```
+    /// # use pipe_trait::*;
+    /// # use std::path::{Path, PathBuf};
+    /// let path = PathBuf::from("/tmp");
+    /// let mut log = Vec::new();
+    /// path.tap_as_ref::<Path, _>(|x| log.push(x.display().to_string()));
+    /// assert_eq!(log, ["/tmp"]);
+    /// ```
+    #[inline]
+    fn tap_as_ref<Param, Function>(&self, f: Function) -> &Self
+    where
+        Self: AsRef<Param>,
+        Param: ?Sized,
+        Function: FnOnce(&Param),
+    {
+        f(self.as_ref());
+        self
+    }
+
+    /// Call `f` with `&mut self` where `f` takes a single parameter of type `Param`
+    /// and `Self` implements trait [`AsMut<Param>`], then return `&mut self`.
+    ///
+    /// ```
+    /// # use pipe_trait::*;
+    /// let mut vec: Vec<i32> = vec![3, 1, 2];
+    /// let len = vec
+    ///     .tap_as_mut(|x: &mut [i32]| x.sort())
+    ///     .pipe_ref(|x| x.len());
+    /// assert_eq!(len, 3);
+    /// assert_eq!(vec, [1, 2, 3]);
+    /// ```
+    #[inline]
+    fn tap_as_mut<Param, Function>(&mut self, f: Function) -> &mut Self
+    where
+        Self: AsMut<Param>,
+        Param: ?Sized,
+        Function: FnOnce(&mut Param),
+    {
+        f(self.as_mut());
+        self
+    }
+
+    /// Call `f` with `&self` where `f` takes a single parameter of type `Param`
+    /// and `Self` implements trait [`Borrow<Param>`], then return `&self`.
+    ///
+    /// ```
+    /// # use pipe_trait::*;
+    /// let x: String = "abc".to_string();
+    /// let len = x
+    ///     .tap_borrow(|x: &str| assert_eq!(x, "abc"))
+    ///     .pipe_ref(|x| x.len());
+    /// assert_eq!(len, 3);
+    /// ```
+    #[inline]
+    fn tap_borrow<Param, Function>(&self, f: Function) -> &Self
+    where
+        Self: Borrow<Param>,
+        Param: ?Sized,
+        Function: FnOnce(&Param),
+    {
+        f(self.borrow());
+        self
+    }
+
+    /// Call `f` with `&mut self` where `f` takes a single parameter of type `Param`
+    /// and `Self` implements trait [`BorrowMut<Param>`], then return `&mut self`.
+    ///
+    /// ```
+    /// # use pipe_trait::*;
+    /// let mut vec: Vec<i32> = vec![0, 1, 2, 3];
+    /// vec.tap_borrow_mut(|x: &mut [i32]| x[0] = 123)
+    ///     .tap_borrow_mut(|x: &mut [i32]| x[1] = 456);
+    /// assert_eq!(vec, [123, 456, 2, 3]);
+    /// ```
+    #[inline]
+    fn tap_borrow_mut<Param, Function>(&mut self, f: Function) -> &mut Self
+    where
+        Self: BorrowMut<Param>,
+        Param: ?Sized,
+        Function: FnOnce(&mut Param),
+    {
+        f(self.borrow_mut());
+        self
+    }
+
+    /// Call `f` with `&self` for its side effect if `condition` is `true`, then return `self`.
+    ///
+    /// `f` is not called when `condition` is `false`.
+    ///
+    /// ```
+    /// # use pipe_trait::*;
+    /// let mut log = Vec::new();
+    /// let x = 12
+    ///     .tap_if(true, |x| log.push(*x))
+    ///     .pipe(|x| x * 2)
+    ///     .tap_if(false, |x| log.push(*x));
+    /// assert_eq!(x, 24);
+    /// assert_eq!(log, [12]);
+    /// ```
+    #[inline]
+    fn tap_if<Function>(self, condition: bool, f: Function) -> Self
+    where
+        Self: Sized,
+        Function: FnOnce(&Self),
+    {
+        if condition {
+            f(&self);
+        }
+        self
+    }
+
+    /// Call `f` with `&self` for its side effect if `predicate` returns `true` for `&self`,
+    /// then return `self`.
+    ///
+    /// ```
+    /// # use pipe_trait::*;
+    /// let mut flagged = Vec::new();
+    /// for total in [500, 1500] {
+    ///     total.tap_when(|x| *x > 1000, |x| flagged.push(*x));
+    /// }
+    /// assert_eq!(flagged, [1500]);
+    /// ```
+    #[inline]
+    fn tap_when<Predicate, Function>(self, predicate: Predicate, f: Function) -> Self
+    where
+        Self: Sized,
+        Predicate: FnOnce(&Self) -> bool,
+        Function: FnOnce(&Self),
+    {
+        if predicate(&self) {
+            f(&self);
+        }
+        self
+    }
+
+    /// Apply fallible `f` to `self`.
+    ///
+    /// This is the same as [`Pipe::pipe`] but restricted to functions returning [`Result`],
+    /// which is meant to be followed by the `?` operator.
+    ///
+    /// ```
+    /// # use pipe_trait::*;
+    /// # use std::num::ParseIntError;
+    /// fn parse(x: &str) -> Result<i32, ParseIntError> {
+    ///   x.parse()
+    /// }
+    /// fn double(x: i32) -> Result<i32, ParseIntError> {
+    ///   Ok(x * 2)
+    /// }
+    /// # fn main() -> Result<(), ParseIntError> {
+    /// let x = "123".try_pipe(parse)?.try_pipe(double)?;
+    /// assert_eq!(x, 246);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    fn try_pipe<Return, Error, Function>(self, f: Function) -> Result<Return, Error>
+    where
+        Self: Sized,
+        Function: FnOnce(Self) -> Result<Return, Error>,
+    {
+        f(self)
+    }
+
+    /// Apply fallible `f` to `&self`.
+    ///
+    /// ```
+    /// # use pipe_trait::*;
+    /// #[derive(Debug, PartialEq, Eq)]
+    /// struct Config {
+    ///     port: u16,
+    /// }
+    /// fn validate(config: &Config) -> Result<u16, &'static str> {
+    ///     if config.port > 1024 {
+    ///         Ok(config.port)
+    ///     } else {
+    ///         Err("reserved port")
+    ///     }
+    /// }
+    /// let config = Config { port: 8080 };
+    /// assert_eq!(config.try_pipe_ref(validate), Ok(8080));
+    /// assert_eq!(config, Config { port: 8080 }); // config is used again
+    /// ```
+    #[inline]
+    fn try_pipe_ref<'a, Return, Error, Function>(&'a self, f: Function) -> Result<Return, Error>
+    where
+        Function: FnOnce(&'a Self) -> Result<Return, Error>,
+    {
+        f(self)
+    }
+
+    /// Apply fallible `f` to `&mut self`.
+    ///
+    /// ```
+    /// # use pipe_trait::*;
+    /// fn push_small(vec: &mut Vec<i32>) -> Result<usize, &'static str> {
+    ///     vec.push(1);
+    ///     if vec.len() > 2 {
+    ///         return Err("too long");
+    ///     }
+    ///     Ok(vec.len())
+    /// }
+    /// let mut vec = Vec::new();
+    /// assert_eq!(vec.try_pipe_mut(push_small), Ok(1));
+    /// assert_eq!(vec.try_pipe_mut(push_small), Ok(2));
+    /// assert_eq!(vec.try_pipe_mut(push_small), Err("too long"));
+    /// assert_eq!(vec, [1, 1, 1]);
+    /// ```
+    #[inline]
+    fn try_pipe_mut<'a, Return, Error, Function>(&'a mut self, f: Function) -> Result<Return, Error>
+    where
+        Function: FnOnce(&'a mut Self) -> Result<Return, Error>,
+    {
+        f(self)
+    }
+
+    /// Apply fallible `f` to `&self` where `f` takes a single parameter of type `Param`
+    /// and `Self` implements trait [`AsRef<Param>`].
+    ///
+    /// If `Self` implements [`AsRef`] for multiple types, `Param` can be specified with a turbofish:
+    ///
+    /// ```
+    /// # use pipe_trait::*;
+    /// # use std::num::ParseIntError;
+    /// fn parse_int(x: &str) -> Result<i32, ParseIntError> {
+    ///   x.parse()
+    /// }
+    /// let x: String = "123".to_string();
+    /// let y = x.try_pipe_as_ref::<str, _, _, _>(parse_int);
+    /// assert_eq!(y, Ok(123));
+    /// ```
+    #[inline]
+    fn try_pipe_as_ref<'a, Param, Return, Error, Function>(
+        &'a self,
+        f: Function,
+    ) -> Result<Return, Error>
+    where
+        Self: AsRef<Param>,
+        Param: ?Sized + 'a,
+        Function: FnOnce(&'a Param) -> Result<Return, Error>,
+    {
+        f(self.as_ref())
+    }
+
+    /// Apply fallible `f` to `&self` where `f` takes a single parameter of type `Param`
+    /// and `Self` implements trait `Deref<Target = Param>`.
+    ///
+    /// ```
+    /// # use pipe_trait::*;
+    /// # use std::num::ParseIntError;
+    /// fn parse_int(x: &str) -> Result<i32, ParseIntError> {
+    ///   x.parse()
+    /// }
+    /// let x: String = "123".to_string();
+    /// assert_eq!(x.try_pipe_deref(parse_int), Ok(123));
+    /// ```
+    #[inline]
+    fn try_pipe_deref<'a, Param, Return, Error, Function>(
+        &'a self,
+        f: Function,
+    ) -> Result<Return, Error>
+    where
+        Self: Deref<Target = Param>,
+        Param: ?Sized + 'a,
+        Function: FnOnce(&'a Param) -> Result<Return, Error>,
+    {
+        f(self)
+    }
+
+    /// Apply fallible `f` to `&self` where `f` takes a single parameter of type `Param`
+    /// and `Self` implements trait [`Borrow<Param>`].
+    ///
+    /// ```
+    /// # use pipe_trait::*;
+    /// # use std::num::ParseIntError;
+    /// fn parse_int(x: &str) -> Result<i32, ParseIntError> {
+    ///   x.parse()
+    /// }
+    /// let x: String = "123".to_string();
+    /// assert_eq!(x.try_pipe_borrow(parse_int), Ok(123));
+    /// ```
+    #[inline]
+    fn try_pipe_borrow<'a, Param, Return, Error, Function>(
+        &'a self,
+        f: Function,
+    ) -> Result<Return, Error>
+    where
+        Self: Borrow<Param>,
+        Param: ?Sized + 'a,
+        Function: FnOnce(&'a Param) -> Result<Return, Error>,
+    {
+        f(self.borrow())
+    }
+
+    /// Apply fallible `f` to `&mut self` where `f` takes a single parameter of type `Param`
+    /// and `Self` implements trait [`AsMut<Param>`].
+    ///
+    /// ```
+    /// # use pipe_trait::*;
+    /// fn increase_all(target: &mut [u8]) -> Result<(), &'static str> {
+    ///     for x in target {
+    ///         *x = x.checked_add(1).ok_or("overflow")?;
+    ///     }
+    ///     Ok(())
+    /// }
+    /// let mut vec: Vec<u8> = vec![0, 1, 2];
+    /// assert_eq!(vec.try_pipe_as_mut(increase_all), Ok(()));
+    /// assert_eq!(vec, [1, 2, 3]);
+    /// ```
+    #[inline]
+    fn try_pipe_as_mut<'a, Param, Return, Error, Function>(
+        &'a mut self,
+        f: Function,
+    ) -> Result<Return, Error>
+    where
+        Self: AsMut<Param>,
+        Param: ?Sized + 'a,
+        Function: FnOnce(&'a mut Param) -> Result<Return, Error>,
+    {
+        f(self.as_mut())
+    }
+
+    /// Apply fallible `f` to `&mut self` where `f` takes a single parameter of type `Param`
+    /// and `Self` implements trait [`DerefMut<Target = Param>`].
+    ///
+    /// ```
+    /// # use pipe_trait::*;
+    /// fn fill(target: &mut [u8]) -> Result<usize, &'static str> {
+    ///     target.fill(1);
+    ///     Ok(target.len())
+    /// }
+    /// let mut boxed: Box<[u8]> = Box::new([0; 3]);
+    /// assert_eq!(boxed.try_pipe_deref_mut(fill), Ok(3));
+    /// assert_eq!(*boxed, [1, 1, 1]);
+    /// ```
+    #[inline]
+    fn try_pipe_deref_mut<'a, Param, Return, Error, Function>(
+        &'a mut self,
+        f: Function,
+    ) -> Result<Return, Error>
+    where
+        Self: DerefMut<Target = Param>,
+        Param: ?Sized + 'a,
+        Function: FnOnce(&'a mut Param) -> Result<Return, Error>,
+    {
+        f(self)
+    }
+
+    /// Apply fallible `f` to `&mut self` where `f` takes a single parameter of type `Param`
+    /// and `Self` implements trait [`BorrowMut<Param>`].
+    ///
+    /// ```
+    /// # use pipe_trait::*;
+    /// fn sort_unique(target: &mut [i32]) -> Result<(), &'static str> {
+    ///     target.sort();
+    ///     if target.windows(2).any(|x| x[0] == x[1]) {
+    ///         return Err("duplicated items");
+    ///     }
+    ///     Ok(())
+    /// }
+    /// let mut vec: Vec<i32> = vec![3, 1, 2];
+    /// assert_eq!(vec.try_pipe_borrow_mut(sort_unique), Ok(()));
+    /// assert_eq!(vec, [1, 2, 3]);
+    /// ```
+    #[inline]
+    fn try_pipe_borrow_mut<'a, Param, Return, Error, Function>(
+        &'a mut self,
+        f: Function,
+    ) -> Result<Return, Error>
+    where
+        Self: BorrowMut<Param>,
+        Param: ?Sized + 'a,
+        Function: FnOnce(&'a mut Param) -> Result<Return, Error>,
+    {
+        f(self.borrow_mut())
+    }
+
+    /// Apply `f` to `self` and `arg`.
+    ///
+    /// ```
+    /// # use pipe_trait::*;
+    /// let x = "ab".pipe_with(3, str::repeat);
+    /// assert_eq!(x, "ababab");
+    /// ```
+    #[inline]
+    fn pipe_with<Arg, Return, Function>(self, arg: Arg, f: Function) -> Return
+    where
+        Self: Sized,
+        Function: FnOnce(Self, Arg) -> Return,
+    {
+        f(self, arg)
+    }
+
+    /// Apply `f` to `self`, `a`, and `b`.
+    ///
+    /// ```
+    /// # use pipe_trait::*;
+    /// let x = [1, 2, 3, 4, 5].pipe_with2(1, 4, |x: [i32; 5], start, end| x[start..end].to_vec());
+    /// assert_eq!(x, [2, 3, 4]);
+    /// ```
+    #[inline]
+    fn pipe_with2<First, Second, Return, Function>(self, a: First, b: Second, f: Function) -> Return
+    where
+        Self: Sized,
+        Function: FnOnce(Self, First, Second) -> Return,
+    {
+        f(self, a, b)
+    }
+
+    /// Apply `f` to `arg` and `self`, in that order.
+    ///
+    /// ```
+    /// # use pipe_trait::*;
+    /// let found = "ll".pipe_flip("hello", str::contains);
+    /// assert!(found);
+    /// ```
+    #[inline]
+    fn pipe_flip<Arg, Return, Function>(self, arg: Arg, f: Function) -> Return
+    where
+        Self: Sized,
+        Function: FnOnce(Arg, Self) -> Return,
+    {
+        f(arg, self)
+    }
+
+    /// Apply `f` to `self` and `tail`, with `tail` bound as the last argument.
+    ///
+    /// This is the same as [`Pipe::pipe_with`], named after the "partial right" helpers found in
+    /// other functional programming libraries.
+    ///
+    /// ```
+    /// # use pipe_trait::*;
+    /// let x = 10.pipe_partial_right(2, |a, b| a / b);
+    /// assert_eq!(x, 5);
+    /// ```
+    #[inline]
+    fn pipe_partial_right<Tail, Return, Function>(self, tail: Tail, f: Function) -> Return
+    where
+        Self: Sized,
+        Function: FnOnce(Self, Tail) -> Return,
+    {
+        self.pipe_with(tail, f)
+    }
+
+    /// Apply every function of `functions` to `self` in order.
+    ///
+    /// ```
+    /// # use pipe_trait::*;
+    /// fn inc(x: i32) -> i32 {
+    ///     x + 1
+    /// }
+    /// fn double(x: i32) -> i32 {
+    ///     x * 2
+    /// }
+    /// assert_eq!(3.pipe_n([inc, double, inc]), 9);
+    /// ```
+    #[inline]
+    fn pipe_n<const N: usize>(self, functions: [fn(Self) -> Self; N]) -> Self
+    where
+        Self: Sized,
+    {
+        functions.iter().fold(self, |x, f| f(x))
+    }
+
+    /// Apply every boxed function of `functions` to `self` in order.
+    ///
+    /// This method requires the `alloc` feature.
+    ///
+    /// ```
+    /// # use pipe_trait::*;
+    /// let offset = 10;
+    /// let steps: Vec<Box<dyn FnOnce(i32) -> i32>> = vec![
+    ///     Box::new(|x| x + 1),
+    ///     Box::new(move |x| x + offset),
+    /// ];
+    /// assert_eq!(3.pipe_fold_fns(steps), 14);
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn pipe_fold_fns<Functions>(self, functions: Functions) -> Self
+    where
+        Self: Sized,
+        Functions: IntoIterator<Item = Box<dyn FnOnce(Self) -> Self>>,
+    {
+        functions.into_iter().fold(self, |x, f| f(x))
+    }
+
+    /// Fold `items` into `self` by applying `f` once per item.
+    ///
+    /// ```
+    /// # use pipe_trait::*;
+    /// let add_header = |mut headers: Vec<String>, (key, value): (&str, &str)| {
+    ///     headers.push(format!("{}: {}", key, value));
+    ///     headers
+    /// };
+    /// let headers = vec!["Host: example.com".to_string()]
+    ///     .pipe_apply_all([("Accept", "*/*"), ("Connection", "close")], add_header);
+    /// assert_eq!(headers, ["Host: example.com", "Accept: */*", "Connection: close"]);
+    /// ```
+    #[inline]
+    fn pipe_apply_all<Items, Function>(self, items: Items, f: Function) -> Self
+    where
+        Self: Sized,
+        Items: IntoIterator,
+        Function: FnMut(Self, Items::Item) -> Self,
+    {
+        items.into_iter().fold(self, f)
+    }
+
+    /// Apply `f` to `self` exactly `n` times.
+    ///
+    /// If `n` is `0`, `self` is returned unchanged without calling `f`.
+    ///
+    /// ```
+    /// # use pipe_trait::*;
+    /// let newton_step = |x: f64| (x + 2.0 / x) / 2.0;
+    /// let sqrt2 = 1.0.pipe_times(5, newton_step);
+    /// assert!((sqrt2 - 2f64.sqrt()).abs() < 1e-10);
+    /// ```
+    #[inline]
+    fn pipe_times<Function>(self, n: usize, f: Function) -> Self
+    where
+        Self: Sized,
+        Function: FnMut(Self) -> Self,
+    {
+        let mut f = f;
+        (0..n).fold(self, |x, _| f(x))
+    }
+
+    /// Apply `f` to `self` repeatedly for as long as `predicate` returns `true`.
+    ///
+    /// `predicate` is checked before each application, so `f` is not called at all
+    /// if `predicate` returns `false` for `self`.
+    ///
+    /// ```
+    /// # use pipe_trait::*;
+    /// let x = 100.pipe_while(|x| *x > 1, |x| x / 2);
+    /// assert_eq!(x, 1);
+    /// ```
+    #[inline]
+    fn pipe_while<Predicate, Function>(self, predicate: Predicate, f: Function) -> Self
+    where
+        Self: Sized,
+        Predicate: FnMut(&Self) -> bool,
+        Function: FnMut(Self) -> Self,
+    {
+        let mut predicate = predicate;
+        let mut f = f;
+        let mut value = self;
+        while predicate(&value) {
+            value = f(value);
+        }
+        value
+    }
+
+    /// Apply `f` to `&self` repeatedly until the result stops changing, then return it.
+    ///
+    /// This method does not return if the value never stops changing.
+    /// Use [`Pipe::pipe_until_bounded`] to limit the number of applications.
+    ///
+    /// ```
+    /// # use pipe_trait::*;
+    /// let simplify = |x: &String| x.replace("--", "-");
+    /// let x = "a-----b".to_string().pipe_until(simplify);
+    /// assert_eq!(x, "a-b");
+    /// ```
+    #[inline]
+    fn pipe_until<Function>(self, f: Function) -> Self
+    where
+        Self: Sized + PartialEq,
+        Function: FnMut(&Self) -> Self,
+    {
+        let mut f = f;
+        let mut value = self;
+        loop {
+            let next = f(&value);
+            if next == value {
+                return value;
+            }
+            value = next;
+        }
+    }
+
+    /// Apply `f` to `&self` repeatedly until the result stops changing, calling `f` at most `max` times.
+    ///
+    /// Return `Ok` of the unchanging value if it is reached, or `Err` of the last value otherwise.
+    ///
+    /// ```
+    /// # use pipe_trait::*;
+    /// let halve = |x: &i32| x / 2;
+    /// assert_eq!(100.pipe_until_bounded(10, halve), Ok(0));
+    /// assert_eq!(100.pipe_until_bounded(3, halve), Err(12));
+    /// ```
+    #[inline]
+    fn pipe_until_bounded<Function>(self, max: usize, f: Function) -> Result<Self, Self>
+    where
+        Self: Sized + PartialEq,
+        Function: FnMut(&Self) -> Self,
+    {
+        let mut f = f;
+        let mut value = self;
+        for _ in 0..max {
+            let next = f(&value);
+            if next == value {
+                return Ok(value);
+            }
+            value = next;
+        }
+        Err(value)
+    }
+
+    /// Apply `f` to `&self` `n` times in succession, collecting every result.
+    ///
+    /// The returned [`Vec`] has exactly `n` elements and does not include `self`.
+    ///
+    /// This method requires the `alloc` feature.
+    ///
+    /// ```
+    /// # use pipe_trait::*;
+    /// let history = 1.pipe_repeat(4, |x| x * 3);
+    /// assert_eq!(history, [3, 9, 27, 81]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn pipe_repeat<Function>(self, n: usize, f: Function) -> Vec<Self>
+    where
+        Self: Sized,
+        Function: FnMut(&Self) -> Self,
+    {
+        let mut f = f;
+        let mut history: Vec<Self> = Vec::with_capacity(n);
+        for _ in 0..n {
+            let next = f(history.last().unwrap_or(&self));
+            history.push(next);
+        }
+        history
+    }
+
+    /// Fold `self` into `init` by applying `f` to both.
+    ///
+    /// ```
+    /// # use pipe_trait::*;
+    /// let values = 12.pipe_reduce(vec![1, 2], |mut acc, x| {
+    ///     acc.push(x);
+    ///     acc
+    /// });
+    /// assert_eq!(values, [1, 2, 12]);
+    /// ```
+    #[inline]
+    fn pipe_reduce<Accumulator, Function>(self, init: Accumulator, f: Function) -> Accumulator
+    where
+        Self: Sized,
+        Function: FnOnce(Accumulator, Self) -> Accumulator,
+    {
+        f(init, self)
+    }
+
+    /// Fold `items` into an accumulator starting from `init` with `fold`,
+    /// then merge the accumulator into `self` with `finish`.
+    ///
+    /// Unlike [`Pipe::pipe_apply_all`], the items are folded into an intermediate accumulator
+    /// whose type may differ from `Self`.
+    ///
+    /// ```
+    /// # use pipe_trait::*;
+    /// #[derive(Debug, PartialEq, Eq)]
+    /// struct Stats {
+    ///     count: usize,
+    ///     total: i32,
+    /// }
+    /// let stats = Stats { count: 1, total: 10 }.pipe_accumulate(
+    ///     [1, 2, 3],
+    ///     (0, 0),
+    ///     |(count, total), x| (count + 1, total + x),
+    ///     |stats, (count, total)| Stats {
+    ///         count: stats.count + count,
+    ///         total: stats.total + total,
+    ///     },
+    /// );
+    /// assert_eq!(stats, Stats { count: 4, total: 16 });
+    /// ```
+    #[inline]
+    fn pipe_accumulate<Items, Accumulator, Fold, Finish>(
+        self,
+        items: Items,
+        init: Accumulator,
+        fold: Fold,
+        finish: Finish,
+    ) -> Self
+    where
+        Self: Sized,
+        Items: IntoIterator,
+        Fold: FnMut(Accumulator, Items::Item) -> Accumulator,
+        Finish: FnOnce(Self, Accumulator) -> Self,
+    {
+        let accumulator = items.into_iter().fold(init, fold);
+        finish(self, accumulator)
+    }
+
+    /// Fold `items` into `self` with `f` like [`Pipe::pipe_apply_all`], collecting every intermediate result.
+    ///
+    /// The returned [`Vec`] has one element per item and does not include `self`.
+    ///
+    /// This method requires the `alloc` feature.
+    ///
+    /// ```
+    /// # use pipe_trait::*;
+    /// let running_totals = 0.pipe_scan([1, 2, 3, 4], |total, x| total + x);
+    /// assert_eq!(running_totals, [1, 3, 6, 10]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn pipe_scan<Items, Function>(self, items: Items, f: Function) -> Vec<Self>
+    where
+        Self: Sized,
+        Items: IntoIterator,
+        Function: FnMut(&Self, Items::Item) -> Self,
+    {
+        let mut f = f;
+        let mut history: Vec<Self> = Vec::new();
+        for item in items {
+            let next = f(history.last().unwrap_or(&self), item);
+            history.push(next);
+        }
+        history
+    }
+
+    /// Convert `self` into `Target` with [`Into`].
+    ///
+    /// ```
+    /// # use pipe_trait::*;
+    /// let x = 12u32.pipe_into::<u64>().pipe(|x| x * u64::from(u32::MAX));
+    /// assert_eq!(x, 51539607540);
+    /// ```
+    #[inline]
+    fn pipe_into<Target>(self) -> Target
+    where
+        Self: Sized + Into<Target>,
+    {
+        self.into()
+    }
+
+    /// Try to convert `self` into `Target` with [`TryInto`].
+    ///
+    /// ```
+    /// # use pipe_trait::*;
+    /// assert_eq!(200i32.pipe_try_into::<u8>(), Ok(200));
+    /// assert!(300i32.pipe_try_into::<u8>().is_err());
+    /// ```
+    #[inline]
+    fn pipe_try_into<Target>(self) -> Result<Target, <Self as TryInto<Target>>::Error>
+    where
+        Self: Sized + TryInto<Target>,
+    {
+        self.try_into()
+    }
+
+    /// Create `Self` from `value` with [`From`].
+    ///
+    /// This is an associated function rather than a method, to be called on the target type,
+    /// the counterpart of [`Pipe::pipe_into`] for when the target type reads better at the call site.
+    ///
+    /// ```
+    /// # use pipe_trait::*;
+    /// let x = String::pipe_from("hello").pipe(|x| x + " world");
+    /// assert_eq!(x, "hello world");
+    /// ```
+    #[inline]
+    fn pipe_from<Source>(value: Source) -> Self
+    where
+        Self: Sized + From<Source>,
+    {
+        Self::from(value)
+    }
+
+    /// Try to create `Self` from `value` with [`TryFrom`].
+    ///
+    /// Like [`Pipe::pipe_from`], this is an associated function to be called on the target type.
+    ///
+    /// ```
+    /// # use pipe_trait::*;
+    /// let bytes: &[u8] = &[1, 2, 3, 4];
+    /// assert_eq!(<[u8; 4]>::pipe_try_from(bytes).ok(), Some([1, 2, 3, 4]));
+    /// assert!(<[u8; 2]>::pipe_try_from(bytes).is_err());
+    /// ```
+    #[inline]
+    fn pipe_try_from<Source>(value: Source) -> Result<Self, <Self as TryFrom<Source>>::Error>
+    where
+        Self: Sized + TryFrom<Source>,
+    {
+        Self::try_from(value)
+    }
+
+    /// Apply conversion function `f` to `self`.
+    ///
+    /// This is the same as [`Pipe::pipe`], named for pipelines that read as a series of conversions.
+    ///
+    /// ```
+    /// # use pipe_trait::*;
+    /// let x = 12.pipe_convert(f64::from);
+    /// assert_eq!(x, 12.0);
+    /// ```
+    #[inline]
+    fn pipe_convert<Return, Function>(self, f: Function) -> Return
+    where
+        Self: Sized,
+        Function: FnOnce(Self) -> Return,
+    {
+        f(self)
+    }
+
+    /// Convert `self` into `Middle`, then into `Target`, with [`Into`].
+    ///
+    /// ```
+    /// # use pipe_trait::*;
+    /// let x = 12u8.pipe_convert_into::<u32, u64>();
+    /// assert_eq!(x, 12u64);
+    /// ```
+    #[inline]
+    fn pipe_convert_into<Middle, Target>(self) -> Target
+    where
+        Self: Sized + Into<Middle>,
+        Middle: Into<Target>,
+    {
+        self.into().into()
+    }
+
+    /// Render `&self` to a [`String`] with [`ToString`].
+    ///
+    /// This method requires the `alloc` feature.
+    ///
+    /// ```
+    /// # use pipe_trait::*;
+    /// let label = 12.pipe_to_string().pipe(|x| format!("count: {}", x));
+    /// assert_eq!(label, "count: 12");
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn pipe_to_string(&self) -> String
+    where
+        Self: Display,
+    {
+        self.to_string()
+    }
+
+    /// Parse `&self` viewed as `&str` into `Target` with [`FromStr`].
+    ///
+    /// The target type is usually given with a turbofish, such as `x.pipe_parse::<i32>()`.
+    ///
+    /// ```
+    /// # use pipe_trait::*;
+    /// let line = "12".to_string();
+    /// let x = line.pipe_parse::<i32>().pipe_ok(|x| x * 2);
+    /// assert_eq!(x, Ok(24));
+    /// ```
+    #[inline]
+    fn pipe_parse<Target>(&self) -> Result<Target, Target::Err>
+    where
+        Self: AsRef<str>,
+        Target: FromStr,
+    {
+        self.as_ref().parse()
+    }
+
+    /// Apply `f` to `&self` viewed as a byte slice via [`AsRef<[u8]>`](AsRef).
+    ///
+    /// ```
+    /// # use pipe_trait::*;
+    /// let checksum = |bytes: &[u8]| bytes.iter().map(|&x| u32::from(x)).sum::<u32>();
+    /// assert_eq!("abc".to_string().pipe_as_bytes(checksum), 294);
+    /// assert_eq!(vec![1u8, 2, 3].pipe_as_bytes(checksum), 6);
+    /// ```
+    #[inline]
+    fn pipe_as_bytes<'a, Return, Function>(&'a self, f: Function) -> Return
+    where
+        Self: AsRef<[u8]>,
+        Function: FnOnce(&'a [u8]) -> Return,
+    {
+        f(self.as_ref())
+    }
+
+    /// Apply async `f` to `self`, returning the future to be awaited.
+    ///
+    /// This method requires the `async` feature.
+    ///
+    /// ```
+    /// # use pipe_trait::*;
+    /// # futures::executor::block_on(async {
+    /// async fn fetch(x: i32) -> i32 {
+    ///     x * 2
+    /// }
+    /// let x = 12.pipe_async(fetch).await.pipe(|x| x + 1);
+    /// assert_eq!(x, 25);
+    /// # });
+    /// ```
+    #[cfg(feature = "async")]
+    #[inline]
+    fn pipe_async<Fut, Function>(self, f: Function) -> Fut
+    where
+        Self: Sized,
+        Function: FnOnce(Self) -> Fut,
+        Fut: Future,
+    {
+        f(self)
+    }
+
+    /// Apply async `f` to `&self`, returning the future to be awaited.
+    ///
+    /// This method requires the `async` feature.
+    ///
+    /// ```
+    /// # use pipe_trait::*;
+    /// # futures::executor::block_on(async {
+    /// async fn len(x: &String) -> usize {
+    ///     x.len()
+    /// }
+    /// let x = "abc".to_string();
+    /// assert_eq!(x.pipe_ref_async(len).await, 3);
+    /// assert_eq!(x, "abc"); // x is used again
+    /// # });
+    /// ```
+    #[cfg(feature = "async")]
+    #[inline]
+    fn pipe_ref_async<'a, Fut, Function>(&'a self, f: Function) -> Fut
+    where
+        Function: FnOnce(&'a Self) -> Fut,
+        Fut: Future + 'a,
+    {
+        f(self)
+    }
+
+    /// Apply async `f` to `&mut self`, returning the future to be awaited.
+    ///
+    /// This method requires the `async` feature.
+    ///
+    /// ```
+    /// # use pipe_trait::*;
+    /// # futures::executor::block_on(async {
+    /// async fn refill(buffer: &mut Vec<u8>) -> usize {
+    ///     buffer.extend_from_slice(b"abc");
+    ///     buffer.len()
+    /// }
+    /// let mut buffer = Vec::new();
+    /// assert_eq!(buffer.pipe_mut_async(refill).await, 3);
+    /// assert_eq!(buffer, b"abc");
+    /// # });
+    /// ```
+    #[cfg(feature = "async")]
+    #[inline]
+    fn pipe_mut_async<'a, Fut, Function>(&'a mut self, f: Function) -> Fut
+    where
+        Function: FnOnce(&'a mut Self) -> Fut,
+        Fut: Future + 'a,
+    {
+        f(self)
+    }
+
+    /// Apply async `f` to `&self` where `f` takes a single parameter of type `Param`
+    /// and `Self` implements trait [`AsRef<Param>`], returning the future to be awaited.
+    ///
+    /// This method requires the `async` feature.
+    ///
+    /// If `Self` implements [`AsRef`] for multiple types, `Param` can be specified with a turbofish:
+    ///
+    /// ```
+    /// # use pipe_trait::*;
+    /// # futures::executor::block_on(async {
+    /// async fn validate(x: &str) -> bool {
+    ///     !x.is_empty()
+    /// }
+    /// let x: String = "abc".to_string();
+    /// assert!(x.pipe_as_ref_async::<str, _, _>(validate).await);
+    /// # });
+    /// ```
+    #[cfg(feature = "async")]
+    #[inline]
+    fn pipe_as_ref_async<'a, Param, Fut, Function>(&'a self, f: Function) -> Fut
+    where
+        Self: AsRef<Param>,
+        Param: ?Sized + 'a,
+        Function: FnOnce(&'a Param) -> Fut,
+        Fut: Future + 'a,
+    {
+        f(self.as_ref())
+    }
+
+    /// Apply async `f` to `&self` where `f` takes a single parameter of type `Param`
+    /// and `Self` implements trait `Deref<Target = Param>`, returning the future to be awaited.
+    ///
+    /// This method requires the `async` feature.
+    ///
+    /// ```
+    /// # use pipe_trait::*;
+    /// # futures::executor::block_on(async {
+    /// async fn len(x: &str) -> usize {
+    ///     x.len()
+    /// }
+    /// let x: String = "abc".to_string();
+    /// assert_eq!(x.pipe_deref_async(len).await, 3);
+    /// # });
+    /// ```
+    #[cfg(feature = "async")]
+    #[inline]
+    fn pipe_deref_async<'a, Param, Fut, Function>(&'a self, f: Function) -> Fut
+    where
+        Self: Deref<Target = Param>,
+        Param: ?Sized + 'a,
+        Function: FnOnce(&'a Param) -> Fut,
+        Fut: Future + 'a,
+    {
+        f(self)
+    }
+
+    /// Apply async `f` to `&self` where `f` takes a single parameter of type `Param`
+    /// and `Self` implements trait [`Borrow<Param>`], returning the future to be awaited.
+    ///
+    /// This method requires the `async` feature.
+    ///
+    /// ```
+    /// # use pipe_trait::*;
+    /// # futures::executor::block_on(async {
+    /// async fn len(x: &str) -> usize {
+    ///     x.len()
+    /// }
+    /// let x: String = "abc".to_string();
+    /// assert_eq!(x.pipe_borrow_async(len).await, 3);
+    /// # });
+    /// ```
+    #[cfg(feature = "async")]
+    #[inline]
+    fn pipe_borrow_async<'a, Param, Fut, Function>(&'a self, f: Function) -> Fut
+    where
+        Self: Borrow<Param>,
+        Param: ?Sized + 'a,
+        Function: FnOnce(&'a Param) -> Fut,
+        Fut: Future + 'a,
+    {
+        f(self.borrow())
+    }
+
+    /// Call async `f` with `&self` for its side effect, then return a future of `self`.
+    ///
+    /// This method requires the `async` feature and the `alloc` feature.
+    ///
+    /// `f` is only called when the returned future is first polled,
+    /// and the future returned by `f` may borrow `self`.
+    ///
+    /// ```
+    /// # use pipe_trait::*;
+    /// # futures::executor::block_on(async {
+    /// struct Response {
+    ///     status: u16,
+    /// }
+    /// async fn check(response: &Response) {
+    ///     assert_eq!(response.status, 200);
+    /// }
+    /// let response = Response { status: 200 }.tap_async(check).await;
+    /// assert_eq!(response.status, 200);
+    /// # });
+    /// ```
+    #[cfg(all(feature = "async", feature = "alloc"))]
+    #[inline]
+    fn tap_async<'a, Function>(self, f: Function) -> future::LocalBoxFuture<'a, Self>
+    where
+        Self: Sized + 'a,
+        Function: for<'b> future::TapAsyncFn<'b, Self> + 'a,
+    {
+        Box::pin(async move {
+            f.call(&self).await;
+            self
+        })
+    }
+
+    /// Apply fallible async `f` to `self`, returning the future to be awaited.
+    ///
+    /// This method requires the `async` feature.
+    ///
+    /// ```
+    /// # use pipe_trait::*;
+    /// # futures::executor::block_on(async {
+    /// async fn send(x: i32) -> Result<i32, &'static str> {
+    ///     Ok(x * 2)
+    /// }
+    /// async fn decode(x: i32) -> Result<String, &'static str> {
+    ///     Ok(x.to_string())
+    /// }
+    /// async fn run(x: i32) -> Result<String, &'static str> {
+    ///     x.try_pipe_async(send).await?.try_pipe_async(decode).await
+    /// }
+    /// assert_eq!(run(12).await.as_deref(), Ok("24"));
+    /// # });
+    /// ```
+    #[cfg(feature = "async")]
+    #[inline]
+    fn try_pipe_async<Return, Error, Fut, Function>(self, f: Function) -> Fut
+    where
+        Self: Sized,
+        Function: FnOnce(Self) -> Fut,
+        Fut: Future<Output = Result<Return, Error>>,
+    {
+        f(self)
+    }
+
+    /// Apply async `f` to `self`, returning a future that fails with
+    /// [`Elapsed`](future::Elapsed) if it does not complete within `duration`.
+    ///
+    /// This method requires the `tokio` feature and must be called within a tokio runtime
+    /// with the time driver enabled.
+    ///
+    /// ```
+    /// # use pipe_trait::*;
+    /// # use std::time::Duration;
+    /// # tokio::runtime::Builder::new_current_thread().enable_time().build().unwrap().block_on(async {
+    /// async fn run(x: i32) -> i32 {
+    ///     x * 2
+    /// }
+    /// let x = 12.pipe_timeout(Duration::from_secs(5), run).await;
+    /// assert_eq!(x, Ok(24));
+    /// # });
+    /// ```
+    #[cfg(feature = "tokio")]
+    #[inline]
+    fn pipe_timeout<Fut, Function>(self, duration: Duration, f: Function) -> future::Timeout<Fut>
+    where
+        Self: Sized,
+        Function: FnOnce(Self) -> Fut,
+        Fut: Future,
+    {
+        tokio::time::timeout(duration, f(self))
+    }
+
+    /// Move `self` into a blocking task that applies `f` to it, returning the task's
+    /// [`JoinHandle`](future::JoinHandle).
+    ///
+    /// Both `self` and `f` are sent to another thread, hence the `Send + 'static` bounds.
+    ///
+    /// This method requires the `tokio` feature and must be called within a tokio runtime.
+    ///
+    /// ```
+    /// # use pipe_trait::*;
+    /// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+    /// let sum = vec![1, 2, 3, 4]
+    ///     .pipe_spawn(|numbers| numbers.iter().sum::<i32>())
+    ///     .await
+    ///     .unwrap();
+    /// assert_eq!(sum, 10);
+    /// # });
+    /// ```
+    #[cfg(feature = "tokio")]
+    #[inline]
+    fn pipe_spawn<Return, Function>(self, f: Function) -> future::JoinHandle<Return>
+    where
+        Self: Sized + Send + 'static,
+        Function: FnOnce(Self) -> Return + Send + 'static,
+        Return: Send + 'static,
+    {
+        tokio::task::spawn_blocking(move || f(self))
+    }
+
+    /// Await `other`, then apply `f` to the pair of `self` and its output.
+    ///
+    /// This method requires the `async` feature.
+    ///
+    /// ```
+    /// # use pipe_trait::*;
+    /// # futures::executor::block_on(async {
+    /// async fn fetch_bonus() -> i32 {
+    ///     30
+    /// }
+    /// let total = 12
+    ///     .pipe_join_await(fetch_bonus(), |(base, bonus)| base + bonus)
+    ///     .await;
+    /// assert_eq!(total, 42);
+    /// # });
+    /// ```
+    #[cfg(feature = "async")]
+    #[inline]
+    fn pipe_join_await<Fut, Return, Function>(
+        self,
+        other: Fut,
+        f: Function,
+    ) -> future::PipeJoinAwait<Self, Fut, Function>
+    where
+        Self: Sized,
+        Fut: Future,
+        Function: FnOnce((Self, Fut::Output)) -> Return,
+    {
+        future::PipeJoinAwait::new(self, other, f)
+    }
+
+    /// Apply async `f` to `self`, then block the current thread until its future completes.
+    ///
+    /// This method requires the `executor` feature.
+    ///
+    /// ```
+    /// # use pipe_trait::*;
+    /// async fn load_remote(key: &str) -> usize {
+    ///     key.len()
+    /// }
+    /// let x = "config".pipe_block_on(load_remote);
+    /// assert_eq!(x, 6);
+    /// ```
+    #[cfg(feature = "executor")]
+    #[inline]
+    fn pipe_block_on<Fut, Function>(self, f: Function) -> Fut::Output
+    where
+        Self: Sized,
+        Function: FnOnce(Self) -> Fut,
+        Fut: Future,
+    {
+        futures::executor::block_on(f(self))
+    }
+
+    /// Apply async `f` to `self`, then poll its future once, returning `Some` of its output
+    /// if it is immediately ready or `None` otherwise.
+    ///
+    /// This method requires the `futures` feature.
+    ///
+    /// ```
+    /// # use pipe_trait::*;
+    /// use futures::future::{pending, ready};
+    /// assert_eq!(12.pipe_now_or_never(ready), Some(12));
+    /// assert_eq!(12.pipe_now_or_never(|_| pending::<i32>()), None);
+    /// ```
+    #[cfg(feature = "futures")]
+    #[inline]
+    fn pipe_now_or_never<Fut, Function>(self, f: Function) -> Option<Fut::Output>
+    where
+        Self: Sized,
+        Function: FnOnce(Self) -> Fut,
+        Fut: Future,
+    {
+        futures::FutureExt::now_or_never(f(self))
+    }
 }
 
 impl<X> Pipe for X {}
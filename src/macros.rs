@@ -0,0 +1,342 @@
+/// Pass a value through a sequence of stages, left to right.
+///
+/// `pipe!(x => f => g => h)` expands to `h(g(f(x)))`.
+///
+/// Each stage is one of:
+/// * An expression that can be called with one argument (a function, a closure, or a path to either),
+///   which is called with the result of the previous stage.
+/// * A method call segment such as `.to_string()` or `.collect::<Vec<_>>()`,
+///   which is called on the result of the previous stage. A turbofish may only contain type arguments.
+/// * A field access segment such as `.name` or `.0`, which is accessed on the result of the previous stage.
+///
+/// A segment is a single `.` followed by a single method call or field access,
+/// so `.name.len()` must be written as `.name => .len()`.
+///
+/// ```
+/// # use pipe_trait::*;
+/// fn double(x: i32) -> i32 {
+///     x * 2
+/// }
+/// let x = pipe!(12 => double => |x| x + 1 => .to_string());
+/// assert_eq!(x, "25");
+/// let y = pipe!((" Hello ", 5) => .0 => .trim() => .len());
+/// assert_eq!(y, 5);
+/// let z = pipe!("a b" => .split(' ') => .collect::<Vec<_>>());
+/// assert_eq!(z, ["a", "b"]);
+/// ```
+#[macro_export]
+macro_rules! pipe {
+    ($value:expr) => {
+        $value
+    };
+
+    ($value:expr => . $method:ident ( $($args:tt)* ) $(=> $($rest:tt)+)?) => {
+        $crate::pipe!($value.$method($($args)*) $(=> $($rest)+)?)
+    };
+
+    ($value:expr => . $method:ident :: < $($generic:ty),+ $(,)? > ( $($args:tt)* ) $(=> $($rest:tt)+)?) => {
+        $crate::pipe!($value.$method::<$($generic),+>($($args)*) $(=> $($rest)+)?)
+    };
+
+    ($value:expr => . $field:tt $(=> $($rest:tt)+)?) => {
+        $crate::pipe!($value.$field $(=> $($rest)+)?)
+    };
+
+    ($value:expr => $f:expr $(=> $($rest:tt)+)?) => {
+        $crate::pipe!($f($value) $(=> $($rest)+)?)
+    };
+}
+
+/// Create a function that passes its argument through a sequence of stages, left to right.
+///
+/// `pipeline!(f, g, h)` evaluates to a closure equivalent to `move |x| h(g(f(x)))`.
+///
+/// Unlike [`pipe!`], which applies the stages immediately, `pipeline!` only evaluates the
+/// stage expressions, so the resulting closure can be stored and applied later.
+/// The closure implements [`Fn`], [`FnMut`], or [`FnOnce`] depending on the stages.
+/// See [`compose!`] for the right-to-left counterpart.
+///
+/// ```
+/// # use pipe_trait::*;
+/// let normalize = pipeline!(str::trim, str::to_lowercase, |x: String| x.replace(' ', "-"));
+/// assert_eq!("  Hello World ".pipe(&normalize), "hello-world");
+/// assert_eq!(" Pipe Trait".pipe(&normalize), "pipe-trait");
+/// ```
+///
+/// The resulting closure takes its input type from the first stage. When that stage is a path
+/// such as `str::trim`, every borrow passed to the closure must share a single lifetime, so a
+/// value borrowed by one call cannot be dropped before the next call. Starting with a closure
+/// whose parameter is annotated, such as `|x: &str| x.trim()`, makes the resulting closure
+/// accept borrows of any lifetime, as long as its output does not borrow from its input.
+/// The body of that first closure is then moved into the resulting closure, as if it were a
+/// `move` closure:
+///
+/// ```
+/// # use pipe_trait::*;
+/// let count = pipeline!(|x: &str| x.trim(), str::len);
+/// let first = String::from(" a ");
+/// assert_eq!(count(&first), 1);
+/// drop(first);
+/// let second = String::from(" bc ");
+/// assert_eq!(count(&second), 2);
+/// ```
+#[macro_export]
+macro_rules! pipeline {
+    (| $param:ident : $input:ty | -> $output:ty $body:block $(, $($rest:expr),+)? $(,)?) => {
+        $crate::pipeline!(
+            @annotated $param: $input,
+            {
+                let output: $output = $body;
+                output
+            },
+            []
+            $(, $($rest),+)?
+        )
+    };
+
+    (| $param:ident : $input:ty | $body:expr $(, $($rest:expr),+)? $(,)?) => {
+        $crate::pipeline!(@annotated $param: $input, $body, [] $(, $($rest),+)?)
+    };
+
+    (@annotated $param:ident : $input:ty, $body:expr, [$($stage:ident)*]) => {
+        move |$param: $input| {
+            let value = $body;
+            $(let value = $stage(value);)*
+            value
+        }
+    };
+
+    (@annotated $param:ident : $input:ty, $body:expr, [$($stage:ident)*], $f:expr $(, $rest:expr)*) => {{
+        let stage = $f;
+        $crate::pipeline!(@annotated $param: $input, $body, [$($stage)* stage] $(, $rest)*)
+    }};
+
+    ($f:expr $(,)?) => {{
+        let f = $f;
+        move |value| f(value)
+    }};
+
+    ($f:expr, $($rest:expr),+ $(,)?) => {{
+        let f = $f;
+        let rest = $crate::pipeline!($($rest),+);
+        move |value| rest(f(value))
+    }};
+}
+
+/// Create a function that passes its argument through a sequence of stages, right to left.
+///
+/// `compose!(h, g, f)` evaluates to a closure equivalent to `move |x| h(g(f(x)))`,
+/// which is the same as `pipeline!(f, g, h)`.
+///
+/// Like [`pipeline!`], it only evaluates the stage expressions, and the resulting closure
+/// implements [`Fn`], [`FnMut`], or [`FnOnce`] depending on the stages.
+///
+/// ```
+/// # use pipe_trait::*;
+/// let inc = |x: i32| x + 1;
+/// let double = |x: i32| x * 2;
+/// assert_eq!(3.pipe(compose!(double, inc)), 8);
+/// assert_eq!(3.pipe(pipeline!(double, inc)), 7);
+/// ```
+#[macro_export]
+macro_rules! compose {
+    ($f:expr $(,)?) => {
+        $crate::pipeline!($f)
+    };
+
+    ($f:expr, $($rest:expr),+ $(,)?) => {{
+        let f = $f;
+        let rest = $crate::compose!($($rest),+);
+        move |value| f(rest(value))
+    }};
+}
+
+/// Call each of a sequence of functions with a reference to a value for their side effects,
+/// then evaluate to the value.
+///
+/// `tap!(x; f; g)` is equivalent to `x.tap(f).tap(g)`.
+///
+/// ```
+/// # use pipe_trait::*;
+/// let mut log = Vec::new();
+/// let x = tap!(12; |x| log.push(*x); |x| assert_eq!(*x, 12));
+/// assert_eq!(x, 12);
+/// assert_eq!(log, [12]);
+/// ```
+#[macro_export]
+macro_rules! tap {
+    ($value:expr $(; $f:expr)* $(;)?) => {{
+        let value = $value;
+        $(
+            let value = $crate::Pipe::tap(value, $f);
+        )*
+        value
+    }};
+}
+
+/// Pass a value through a sequence of fallible stages, left to right, applying the `?`
+/// operator to the output of every stage.
+///
+/// `try_pipe!(x => f => g => h)` expands to `h(g(f(x)?)?)?`, so it short-circuits on
+/// the first failing stage and can only be used where `?` can.
+///
+/// Like [`pipe!`], each stage is a callable expression, a method call segment, or a field
+/// access segment, parsed by the same rules. A field access segment applies `?` to the field,
+/// so `try_pipe!(x => .0)` expands to `x.0?`.
+///
+/// ```
+/// # use pipe_trait::*;
+/// fn parse(text: &str) -> Result<i32, String> {
+///     text.parse().map_err(|_| format!("not a number: {}", text))
+/// }
+/// fn validate(x: i32) -> Result<i32, String> {
+///     if x >= 0 { Ok(x) } else { Err(format!("negative: {}", x)) }
+/// }
+/// fn run(text: &str) -> Result<i32, String> {
+///     let x = try_pipe!(text => parse => validate);
+///     Ok(x * 2)
+/// }
+/// assert_eq!(run("12"), Ok(24));
+/// assert_eq!(run("-12"), Err("negative: -12".to_string()));
+/// assert_eq!(run("abc"), Err("not a number: abc".to_string()));
+/// ```
+#[macro_export]
+macro_rules! try_pipe {
+    ($value:expr) => {
+        $value
+    };
+
+    ($value:expr => . $method:ident ( $($args:tt)* ) $(=> $($rest:tt)+)?) => {
+        $crate::try_pipe!($value.$method($($args)*)? $(=> $($rest)+)?)
+    };
+
+    ($value:expr => . $method:ident :: < $($generic:ty),+ $(,)? > ( $($args:tt)* ) $(=> $($rest:tt)+)?) => {
+        $crate::try_pipe!($value.$method::<$($generic),+>($($args)*)? $(=> $($rest)+)?)
+    };
+
+    ($value:expr => . $field:tt $(=> $($rest:tt)+)?) => {
+        $crate::try_pipe!($value.$field? $(=> $($rest)+)?)
+    };
+
+    ($value:expr => $f:expr $(=> $($rest:tt)+)?) => {
+        $crate::try_pipe!($f($value)? $(=> $($rest)+)?)
+    };
+}
+
+/// Match a value against a set of arms and evaluate to the chosen arm.
+///
+/// `pipe_match!(x { pattern => expr, ... })` expands to `match x { pattern => expr, ... }`,
+/// so arms support bindings and guards just like a normal `match`.
+///
+/// ```
+/// # use pipe_trait::*;
+/// let describe = |x: i32| pipe_match!(x {
+///     0 => "zero",
+///     x if x < 0 => "negative",
+///     _ => "positive",
+/// });
+/// assert_eq!((-3).pipe(describe), "negative");
+/// assert_eq!(pipe_match!(2 * 3 - 6 { 0 => "zero", _ => "other" }), "zero");
+/// ```
+///
+/// The value is read one token tree at a time, and every token tree costs one level of macro
+/// recursion, so a value made of many tokens can exceed the recursion limit. Wrapping such a
+/// value in a block, as in `pipe_match!({ long + value } { ... })`, turns it into a single
+/// token tree, but moves the value like any other block would.
+///
+/// A value is required before the arms:
+///
+/// ```compile_fail
+/// # use pipe_trait::*;
+/// let x = pipe_match!({ _ => 0 });
+/// ```
+#[macro_export]
+macro_rules! pipe_match {
+    (@value [] { $($arms:tt)* }) => {
+        compile_error!("expected a value before the match arms")
+    };
+
+    (@value [$($value:tt)+] { $($arms:tt)* }) => {
+        match $($value)+ {
+            $($arms)*
+        }
+    };
+
+    (@value [$($value:tt)*] $next:tt $($rest:tt)+) => {
+        $crate::pipe_match!(@value [$($value)* $next] $($rest)+)
+    };
+
+    (@value $($input:tt)*) => {
+        compile_error!("expected match arms in braces after the value")
+    };
+
+    ($($input:tt)+) => {
+        $crate::pipe_match!(@value [] $($input)+)
+    };
+}
+
+/// Pass a value through a sequence of stages like [`pipe!`], with the ability to name
+/// intermediate values.
+///
+/// In addition to the stages supported by [`pipe!`], a `let name; expr` stage binds the
+/// result of the previous stage to `name`, then continues the pipeline with `expr`.
+/// `name` stays in scope for `expr` and every stage after it.
+///
+/// `pipe_let!(x => f => let y; g(&y) => h)` expands to `{ let y = f(x); h(g(&y)) }`.
+///
+/// ```
+/// # use pipe_trait::*;
+/// let x = pipe_let!(" Hello " => str::trim => let trimmed; trimmed.len() => |len| (trimmed, len));
+/// assert_eq!(x, ("Hello", 5));
+/// ```
+#[macro_export]
+macro_rules! pipe_let {
+    ($value:expr) => {
+        $value
+    };
+
+    ($value:expr => let $name:ident; $($rest:tt)+) => {{
+        let $name = $value;
+        $crate::pipe_let!($($rest)+)
+    }};
+
+    ($value:expr => . $method:ident ( $($args:tt)* ) $(=> $($rest:tt)+)?) => {
+        $crate::pipe_let!($value.$method($($args)*) $(=> $($rest)+)?)
+    };
+
+    ($value:expr => . $method:ident :: < $($generic:ty),+ $(,)? > ( $($args:tt)* ) $(=> $($rest:tt)+)?) => {
+        $crate::pipe_let!($value.$method::<$($generic),+>($($args)*) $(=> $($rest)+)?)
+    };
+
+    ($value:expr => . $field:tt $(=> $($rest:tt)+)?) => {
+        $crate::pipe_let!($value.$field $(=> $($rest)+)?)
+    };
+
+    ($value:expr => $f:expr $(=> $($rest:tt)+)?) => {
+        $crate::pipe_let!($f($value) $(=> $($rest)+)?)
+    };
+}
+
+/// Create a closure that accesses fields or calls methods on its argument.
+///
+/// `pipe_field!(.a.b())` evaluates to `|x| x.a.b()`: the tokens after the closure parameter
+/// are exactly the tokens given to the macro, which must start with a `.` and may chain any
+/// number of field accesses and method calls.
+///
+/// The type of the argument is inferred from where the closure is used, such as [`Pipe::pipe`].
+///
+/// ```
+/// # use pipe_trait::*;
+/// struct User {
+///     name: &'static str,
+/// }
+/// let user = User { name: "alice" };
+/// assert_eq!(user.pipe(pipe_field!(.name.to_uppercase())), "ALICE");
+/// assert_eq!("hello".pipe(pipe_field!(.len())), 5);
+/// ```
+#[macro_export]
+macro_rules! pipe_field {
+    (. $($access:tt)+) => {
+        |value| value . $($access)+
+    };
+}
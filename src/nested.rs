@@ -0,0 +1,60 @@
+/// [`Option`] of [`Result`] and [`Result`] of [`Option`] implement this trait.
+pub trait PipeTranspose {
+    /// Type of the transposed value.
+    type Output;
+
+    /// Transpose an [`Option`] of a [`Result`] into a [`Result`] of an [`Option`] and vice versa.
+    ///
+    /// ```
+    /// # use pipe_trait::*;
+    /// let x: Option<Result<i32, &str>> = Some(Ok(12));
+    /// let y: Result<Option<i32>, &str> = Ok(Some(12));
+    /// assert_eq!(x.pipe_transpose(), y);
+    /// assert_eq!(y.pipe_transpose(), x);
+    /// ```
+    fn pipe_transpose(self) -> Self::Output;
+}
+
+impl<Value, Error> PipeTranspose for Option<Result<Value, Error>> {
+    type Output = Result<Option<Value>, Error>;
+
+    #[inline]
+    fn pipe_transpose(self) -> Self::Output {
+        self.transpose()
+    }
+}
+
+impl<Value, Error> PipeTranspose for Result<Option<Value>, Error> {
+    type Output = Option<Result<Value, Error>>;
+
+    #[inline]
+    fn pipe_transpose(self) -> Self::Output {
+        self.transpose()
+    }
+}
+
+/// [`Result`] of [`Result`] with the same error type implements this trait.
+pub trait PipeFlattenResult<Value, Error> {
+    /// Collapse a nested [`Result`] into a single [`Result`].
+    ///
+    /// ```
+    /// # use pipe_trait::*;
+    /// fn half(x: i32) -> Result<Result<i32, &'static str>, &'static str> {
+    ///     if x < 0 {
+    ///         return Err("negative");
+    ///     }
+    ///     Ok(if x % 2 == 0 { Ok(x / 2) } else { Err("odd") })
+    /// }
+    /// assert_eq!(half(12).pipe_flatten_result(), Ok(6));
+    /// assert_eq!(half(13).pipe_flatten_result(), Err("odd"));
+    /// assert_eq!(half(-12).pipe_flatten_result(), Err("negative"));
+    /// ```
+    fn pipe_flatten_result(self) -> Result<Value, Error>;
+}
+
+impl<Value, Error> PipeFlattenResult<Value, Error> for Result<Result<Value, Error>, Error> {
+    #[inline]
+    fn pipe_flatten_result(self) -> Result<Value, Error> {
+        self.and_then(|x| x)
+    }
+}
@@ -0,0 +1,267 @@
+/// All [`Option`] types implement this trait.
+pub trait PipeOption<Value> {
+    /// Call `f` with `&value` for its side effect if `self` is `Some(value)`, then return `self`.
+    ///
+    /// ```
+    /// # use pipe_trait::*;
+    /// let mut log = Vec::new();
+    /// assert_eq!(Some(12).tap_some(|x| log.push(*x)), Some(12));
+    /// assert_eq!(None.tap_some(|x| log.push(*x)), None);
+    /// assert_eq!(log, [12]);
+    /// ```
+    fn tap_some<Function>(self, f: Function) -> Self
+    where
+        Function: FnOnce(&Value);
+
+    /// Call `f` for its side effect if `self` is [`None`], then return `self`.
+    ///
+    /// ```
+    /// # use pipe_trait::*;
+    /// let mut misses = 0;
+    /// assert_eq!(Some(12).tap_none(|| misses += 1), Some(12));
+    /// assert_eq!(None::<i32>.tap_none(|| misses += 1), None);
+    /// assert_eq!(misses, 1);
+    /// ```
+    fn tap_none<Function>(self, f: Function) -> Self
+    where
+        Function: FnOnce();
+
+    /// Apply `f` to `value` if `self` is `Some(value)`, leaving `None` untouched.
+    ///
+    /// ```
+    /// # use pipe_trait::*;
+    /// #[derive(Debug, PartialEq, Eq)]
+    /// struct Foo(i32);
+    /// let double = |x| x * 2;
+    /// assert_eq!(Some(12).pipe_some(double).pipe_some(Foo), Some(Foo(24)));
+    /// assert_eq!(None.pipe_some(double).pipe_some(Foo), None);
+    /// ```
+    fn pipe_some<Return, Function>(self, f: Function) -> Option<Return>
+    where
+        Function: FnOnce(Value) -> Return;
+
+    /// Return `self` if it is [`Some`], otherwise call `f` to produce a fallback.
+    ///
+    /// `f` is not called when `self` is [`Some`].
+    ///
+    /// ```
+    /// # use pipe_trait::*;
+    /// let load_from_disk = || Some(34);
+    /// assert_eq!(Some(12).pipe_none(load_from_disk), Some(12));
+    /// assert_eq!(None.pipe_none(load_from_disk), Some(34));
+    /// ```
+    fn pipe_none<Function>(self, f: Function) -> Option<Value>
+    where
+        Function: FnOnce() -> Option<Value>;
+
+    /// Transform `Some(value)` into `Ok(value)` and [`None`] into `Err(error)`.
+    ///
+    /// ```
+    /// # use pipe_trait::*;
+    /// #[derive(Debug, PartialEq, Eq)]
+    /// struct NotFound;
+    /// assert_eq!(Some(12).pipe_ok_or(NotFound).pipe_ok(|x| x * 2), Ok(24));
+    /// assert_eq!(None::<i32>.pipe_ok_or(NotFound).pipe_ok(|x| x * 2), Err(NotFound));
+    /// ```
+    fn pipe_ok_or<Error>(self, error: Error) -> Result<Value, Error>;
+
+    /// Transform `Some(value)` into `Ok(value)` and [`None`] into `Err(f())`.
+    ///
+    /// `f` is not called when `self` is [`Some`].
+    ///
+    /// ```
+    /// # use pipe_trait::*;
+    /// #[derive(Debug, PartialEq, Eq)]
+    /// struct NotFound;
+    /// assert_eq!(Some(12).pipe_ok_or_else(|| NotFound), Ok(12));
+    /// assert_eq!(None::<i32>.pipe_ok_or_else(|| NotFound), Err(NotFound));
+    /// ```
+    fn pipe_ok_or_else<Error, Function>(self, f: Function) -> Result<Value, Error>
+    where
+        Function: FnOnce() -> Error;
+
+    /// Unwrap `self` with `default` as fallback, then apply `f` to the result.
+    ///
+    /// ```
+    /// # use pipe_trait::*;
+    /// let double = |x| x * 2;
+    /// assert_eq!(Some(12).pipe_unwrap_or_pipe(0, double), 24);
+    /// assert_eq!(None.pipe_unwrap_or_pipe(3, double), 6);
+    /// ```
+    fn pipe_unwrap_or_pipe<Return, Function>(self, default: Value, f: Function) -> Return
+    where
+        Function: FnOnce(Value) -> Return;
+
+    /// Unwrap `self` with the result of `default` as fallback, then apply `f` to the result.
+    ///
+    /// `default` is not called when `self` is [`Some`].
+    ///
+    /// ```
+    /// # use pipe_trait::*;
+    /// let double = |x| x * 2;
+    /// assert_eq!(Some(12).pipe_unwrap_or_else_pipe(|| 0, double), 24);
+    /// assert_eq!(None.pipe_unwrap_or_else_pipe(|| 3, double), 6);
+    /// ```
+    fn pipe_unwrap_or_else_pipe<Return, Fallback, Function>(
+        self,
+        default: Fallback,
+        f: Function,
+    ) -> Return
+    where
+        Fallback: FnOnce() -> Value,
+        Function: FnOnce(Value) -> Return;
+
+    /// Return `self` if it is `Some(value)` and `predicate` returns `true` for `&value`,
+    /// otherwise return [`None`].
+    ///
+    /// ```
+    /// # use pipe_trait::*;
+    /// let unprivileged = |port: &u16| *port > 1024;
+    /// assert_eq!(Some(8080).pipe_filter(unprivileged), Some(8080));
+    /// assert_eq!(Some(80).pipe_filter(unprivileged), None);
+    /// ```
+    fn pipe_filter<Predicate>(self, predicate: Predicate) -> Option<Value>
+    where
+        Predicate: FnOnce(&Value) -> bool;
+
+    /// Return [`Some`] if exactly one of `self` and `other` is [`Some`], otherwise return [`None`].
+    ///
+    /// ```
+    /// # use pipe_trait::*;
+    /// let flag = Some("flag");
+    /// let env = None;
+    /// assert_eq!(flag.pipe_xor(env), Some("flag"));
+    /// assert_eq!(flag.pipe_xor(Some("env")), None);
+    /// ```
+    fn pipe_xor(self, other: Option<Value>) -> Option<Value>;
+
+    /// Insert the result of `f` into `self` if it is [`None`], then return a mutable reference
+    /// to the contained value.
+    ///
+    /// `f` is not called when `self` is [`Some`].
+    ///
+    /// ```
+    /// # use pipe_trait::*;
+    /// let mut cache: Option<Vec<i32>> = None;
+    /// cache.pipe_get_or_insert_with(Vec::new).push(1);
+    /// cache.pipe_get_or_insert_with(Vec::new).push(2);
+    /// assert_eq!(cache, Some(vec![1, 2]));
+    /// ```
+    fn pipe_get_or_insert_with<Function>(&mut self, f: Function) -> &mut Value
+    where
+        Function: FnOnce() -> Value;
+
+    /// Return `value` if `self` is `Some(value)`, otherwise return [`Default::default()`].
+    ///
+    /// ```
+    /// # use pipe_trait::*;
+    /// let settings: Option<Vec<i32>> = None;
+    /// assert_eq!(settings.pipe_or_default().pipe(|x| x.len()), 0);
+    /// ```
+    fn pipe_or_default(self) -> Value
+    where
+        Value: Default;
+}
+
+impl<Value> PipeOption<Value> for Option<Value> {
+    #[inline]
+    fn tap_some<Function>(self, f: Function) -> Self
+    where
+        Function: FnOnce(&Value),
+    {
+        if let Some(value) = &self {
+            f(value);
+        }
+        self
+    }
+
+    #[inline]
+    fn tap_none<Function>(self, f: Function) -> Self
+    where
+        Function: FnOnce(),
+    {
+        if self.is_none() {
+            f();
+        }
+        self
+    }
+
+    #[inline]
+    fn pipe_some<Return, Function>(self, f: Function) -> Option<Return>
+    where
+        Function: FnOnce(Value) -> Return,
+    {
+        self.map(f)
+    }
+
+    #[inline]
+    fn pipe_none<Function>(self, f: Function) -> Option<Value>
+    where
+        Function: FnOnce() -> Option<Value>,
+    {
+        self.or_else(f)
+    }
+
+    #[inline]
+    fn pipe_ok_or<Error>(self, error: Error) -> Result<Value, Error> {
+        self.ok_or(error)
+    }
+
+    #[inline]
+    fn pipe_ok_or_else<Error, Function>(self, f: Function) -> Result<Value, Error>
+    where
+        Function: FnOnce() -> Error,
+    {
+        self.ok_or_else(f)
+    }
+
+    #[inline]
+    fn pipe_unwrap_or_pipe<Return, Function>(self, default: Value, f: Function) -> Return
+    where
+        Function: FnOnce(Value) -> Return,
+    {
+        f(self.unwrap_or(default))
+    }
+
+    #[inline]
+    fn pipe_unwrap_or_else_pipe<Return, Fallback, Function>(
+        self,
+        default: Fallback,
+        f: Function,
+    ) -> Return
+    where
+        Fallback: FnOnce() -> Value,
+        Function: FnOnce(Value) -> Return,
+    {
+        f(self.unwrap_or_else(default))
+    }
+
+    #[inline]
+    fn pipe_filter<Predicate>(self, predicate: Predicate) -> Option<Value>
+    where
+        Predicate: FnOnce(&Value) -> bool,
+    {
+        self.filter(predicate)
+    }
+
+    #[inline]
+    fn pipe_xor(self, other: Option<Value>) -> Option<Value> {
+        self.xor(other)
+    }
+
+    #[inline]
+    fn pipe_get_or_insert_with<Function>(&mut self, f: Function) -> &mut Value
+    where
+        Function: FnOnce() -> Value,
+    {
+        self.get_or_insert_with(f)
+    }
+
+    #[inline]
+    fn pipe_or_default(self) -> Value
+    where
+        Value: Default,
+    {
+        self.unwrap_or_default()
+    }
+}
@@ -0,0 +1,61 @@
+/// Bind `a` as the first argument of `f`, creating a function of the remaining argument.
+///
+/// `curry(f, a)` is equivalent to `move |b| f(a, b)`.
+///
+/// ```
+/// # use pipe_trait::*;
+/// let add = |a: i32, b: i32| a + b;
+/// assert_eq!(5.pipe(curry(add, 10)), 15);
+/// ```
+#[inline]
+pub fn curry<First, Second, Return, Function>(
+    f: Function,
+    a: First,
+) -> impl FnOnce(Second) -> Return
+where
+    Function: FnOnce(First, Second) -> Return,
+{
+    move |b| f(a, b)
+}
+
+/// Bind `b` as the second argument of `f`, creating a function of the remaining argument.
+///
+/// `curry_right(f, b)` is equivalent to `move |a| f(a, b)`.
+///
+/// ```
+/// # use pipe_trait::*;
+/// let sub = |a: i32, b: i32| a - b;
+/// assert_eq!(5.pipe(curry_right(sub, 10)), -5);
+/// ```
+#[inline]
+pub fn curry_right<First, Second, Return, Function>(
+    f: Function,
+    b: Second,
+) -> impl FnOnce(First) -> Return
+where
+    Function: FnOnce(First, Second) -> Return,
+{
+    move |a| f(a, b)
+}
+
+/// Bind `tail` as the last argument of `f`, creating a function of the remaining argument.
+///
+/// This is the same as [`curry_right`], named after the "partial right" helpers found in
+/// other functional programming libraries.
+///
+/// ```
+/// # use pipe_trait::*;
+/// let divide = |a: i32, b: i32| a / b;
+/// let halve = partial_right(divide, 2);
+/// assert_eq!(halve(10), 5);
+/// ```
+#[inline]
+pub fn partial_right<Head, Tail, Return, Function>(
+    f: Function,
+    tail: Tail,
+) -> impl FnOnce(Head) -> Return
+where
+    Function: FnOnce(Head, Tail) -> Return,
+{
+    curry_right(f, tail)
+}
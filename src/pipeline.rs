@@ -0,0 +1,212 @@
+#[cfg(feature = "async")]
+use crate::future::BoxFuture;
+use alloc::boxed::Box;
+#[cfg(feature = "async")]
+use core::future::Future;
+
+/// Function from `In` to `Out` assembled from stages at runtime.
+///
+/// The stages may borrow data that lives for `'a`, and `In` may itself be a borrow.
+///
+/// This type requires the `alloc` feature.
+///
+/// ```
+/// # use pipe_trait::*;
+/// let pipeline = Pipeline::new(str::trim)
+///     .then(str::len)
+///     .then(|len| len * 2);
+/// assert_eq!(pipeline.apply("  hello "), 10);
+/// ```
+pub struct Pipeline<'a, In, Out> {
+    function: Box<dyn FnOnce(In) -> Out + 'a>,
+}
+
+impl<'a, In, Out> Pipeline<'a, In, Out> {
+    /// Create a pipeline of a single stage.
+    #[inline]
+    pub fn new<Function>(f: Function) -> Self
+    where
+        Function: FnOnce(In) -> Out + 'a,
+    {
+        Pipeline {
+            function: Box::new(f),
+        }
+    }
+
+    /// Append a stage that applies `f` to the output of the pipeline.
+    #[inline]
+    pub fn then<Return, Function>(self, f: Function) -> Pipeline<'a, In, Return>
+    where
+        In: 'a,
+        Out: 'a,
+        Function: FnOnce(Out) -> Return + 'a,
+    {
+        let function = self.function;
+        Pipeline::new(move |x| f(function(x)))
+    }
+
+    /// Pass `value` through every stage of the pipeline.
+    #[inline]
+    pub fn apply(self, value: In) -> Out {
+        (self.function)(value)
+    }
+}
+
+impl<'a, Value> Pipeline<'a, Value, Value> {
+    /// Create a pipeline without stages, which returns its input unchanged.
+    ///
+    /// ```
+    /// # use pipe_trait::*;
+    /// let stages = [1, 2, 3];
+    /// let pipeline = stages
+    ///     .iter()
+    ///     .fold(Pipeline::identity(), |pipeline, &step| pipeline.then(move |x| x * 10 + step));
+    /// assert_eq!(pipeline.apply(0), 123);
+    /// ```
+    #[inline]
+    pub fn identity() -> Self
+    where
+        Value: 'a,
+    {
+        Pipeline::new(|x| x)
+    }
+}
+
+/// Fallible function from `In` to `Out` assembled from stages at runtime.
+///
+/// Every stage may fail with `Error`, which skips the remaining stages.
+/// Like [`Pipeline`], the stages and `In` may borrow data that lives for `'a`.
+///
+/// This type requires the `alloc` feature.
+///
+/// ```
+/// # use pipe_trait::*;
+/// let pipeline = ResultPipeline::new(|x: &str| x.parse::<i32>().map_err(|_| "not a number"))
+///     .and_then(|x| if x > 0 { Ok(x) } else { Err("not positive") })
+///     .and_then(|x| Ok(x * 2));
+/// assert_eq!(pipeline.apply("21"), Ok(42));
+/// ```
+pub struct ResultPipeline<'a, In, Out, Error> {
+    function: Box<dyn FnOnce(In) -> Result<Out, Error> + 'a>,
+}
+
+impl<'a, In, Out, Error> ResultPipeline<'a, In, Out, Error> {
+    /// Create a pipeline of a single fallible stage.
+    #[inline]
+    pub fn new<Function>(f: Function) -> Self
+    where
+        Function: FnOnce(In) -> Result<Out, Error> + 'a,
+    {
+        ResultPipeline {
+            function: Box::new(f),
+        }
+    }
+
+    /// Append a fallible stage that applies `f` to the output of the pipeline if it succeeds.
+    #[inline]
+    pub fn and_then<Return, Function>(self, f: Function) -> ResultPipeline<'a, In, Return, Error>
+    where
+        In: 'a,
+        Out: 'a,
+        Error: 'a,
+        Function: FnOnce(Out) -> Result<Return, Error> + 'a,
+    {
+        let function = self.function;
+        ResultPipeline::new(move |x| function(x).and_then(f))
+    }
+
+    /// Pass `value` through every stage of the pipeline until one of them fails.
+    #[inline]
+    pub fn apply(self, value: In) -> Result<Out, Error> {
+        (self.function)(value)
+    }
+}
+
+impl<'a, Value, Error> ResultPipeline<'a, Value, Value, Error> {
+    /// Create a pipeline without stages, which returns its input unchanged as `Ok`.
+    #[inline]
+    pub fn identity() -> Self
+    where
+        Value: 'a,
+        Error: 'a,
+    {
+        ResultPipeline::new(Ok)
+    }
+}
+
+/// Async function from `In` to `Out` assembled from stages at runtime.
+///
+/// Like [`Pipeline`], the stages and `In` may borrow data that lives for `'a`.
+///
+/// Every stage and the future it returns must be [`Send`], so that the future of
+/// [`apply`](AsyncPipeline::apply) can be spawned onto a multi-threaded executor.
+/// As a trade-off, stages that hold non-`Send` data such as `Rc` are rejected.
+///
+/// This type requires both the `async` feature and the `alloc` feature.
+///
+/// ```
+/// # use pipe_trait::*;
+/// # futures::executor::block_on(async {
+/// async fn fetch(id: u32) -> String {
+///     format!("user-{}", id)
+/// }
+/// async fn shout(name: String) -> String {
+///     name.to_uppercase()
+/// }
+/// let pipeline = AsyncPipeline::new(fetch).then_async(shout);
+/// assert_eq!(pipeline.apply(12).await, "USER-12");
+/// # });
+/// ```
+#[cfg(feature = "async")]
+pub struct AsyncPipeline<'a, In, Out> {
+    function: Box<dyn FnOnce(In) -> BoxFuture<'a, Out> + Send + 'a>,
+}
+
+#[cfg(feature = "async")]
+impl<'a, In, Out> AsyncPipeline<'a, In, Out> {
+    /// Create a pipeline of a single async stage.
+    #[inline]
+    pub fn new<Fut, Function>(f: Function) -> Self
+    where
+        Function: FnOnce(In) -> Fut + Send + 'a,
+        Fut: Future<Output = Out> + Send + 'a,
+    {
+        AsyncPipeline {
+            function: Box::new(move |x| Box::pin(f(x))),
+        }
+    }
+
+    /// Append an async stage that applies `f` to the output of the pipeline.
+    #[inline]
+    pub fn then_async<Fut, Function>(self, f: Function) -> AsyncPipeline<'a, In, Fut::Output>
+    where
+        In: Send + 'a,
+        Out: 'a,
+        Function: FnOnce(Out) -> Fut + Send + 'a,
+        Fut: Future + Send + 'a,
+    {
+        let function = self.function;
+        AsyncPipeline::new(move |x| async move {
+            let future = f(function(x).await);
+            future.await
+        })
+    }
+
+    /// Pass `value` through every stage of the pipeline, returning the future to be awaited.
+    #[inline]
+    pub fn apply(self, value: In) -> BoxFuture<'a, Out> {
+        (self.function)(value)
+    }
+}
+
+#[cfg(feature = "async")]
+impl<'a, Value> AsyncPipeline<'a, Value, Value> {
+    /// Create a pipeline without stages, whose future resolves to its input unchanged.
+    #[inline]
+    pub fn identity() -> Self
+    where
+        Value: Send + 'a,
+    {
+        AsyncPipeline::new(core::future::ready)
+    }
+}
@@ -0,0 +1,318 @@
+#[cfg(feature = "async")]
+use crate::future::PipeOkAsync;
+#[cfg(feature = "async")]
+use core::future::Future;
+
+/// All [`Result`] types implement this trait.
+pub trait PipeResult<Value, Error> {
+    /// Call `f` with `&value` for its side effect if `self` is `Ok(value)`, then return `self`.
+    ///
+    /// ```
+    /// # use pipe_trait::*;
+    /// let mut log = Vec::new();
+    /// let ok: Result<i32, &str> = Ok(12);
+    /// let err: Result<i32, &str> = Err("failed");
+    /// assert_eq!(ok.tap_ok(|x| log.push(*x)), Ok(12));
+    /// assert_eq!(err.tap_ok(|x| log.push(*x)), Err("failed"));
+    /// assert_eq!(log, [12]);
+    /// ```
+    fn tap_ok<Function>(self, f: Function) -> Self
+    where
+        Function: FnOnce(&Value);
+
+    /// Call `f` with `&error` for its side effect if `self` is `Err(error)`, then return `self`.
+    ///
+    /// ```
+    /// # use pipe_trait::*;
+    /// let mut log = Vec::new();
+    /// let ok: Result<i32, &str> = Ok(12);
+    /// let err: Result<i32, &str> = Err("failed");
+    /// assert_eq!(ok.tap_err(|x| log.push(*x)), Ok(12));
+    /// assert_eq!(err.tap_err(|x| log.push(*x)), Err("failed"));
+    /// assert_eq!(log, ["failed"]);
+    /// ```
+    fn tap_err<Function>(self, f: Function) -> Self
+    where
+        Function: FnOnce(&Error);
+
+    /// Apply `f` to `value` if `self` is `Ok(value)`, leaving `Err` untouched.
+    ///
+    /// ```
+    /// # use pipe_trait::*;
+    /// #[derive(Debug, PartialEq, Eq)]
+    /// struct Foo(i32);
+    /// let double = |x| x * 2;
+    /// let ok: Result<i32, &str> = Ok(12);
+    /// let err: Result<i32, &str> = Err("failed");
+    /// assert_eq!(ok.pipe_ok(double).pipe_ok(Foo), Ok(Foo(24)));
+    /// assert_eq!(err.pipe_ok(double).pipe_ok(Foo), Err("failed"));
+    /// ```
+    fn pipe_ok<Return, Function>(self, f: Function) -> Result<Return, Error>
+    where
+        Function: FnOnce(Value) -> Return;
+
+    /// Apply `f` to `error` if `self` is `Err(error)`, leaving `Ok` untouched.
+    ///
+    /// ```
+    /// # use pipe_trait::*;
+    /// #[derive(Debug, PartialEq, Eq)]
+    /// struct MyError(&'static str);
+    /// let ok: Result<i32, &str> = Ok(12);
+    /// let err: Result<i32, &str> = Err("failed");
+    /// assert_eq!(ok.pipe_err(MyError), Ok(12));
+    /// assert_eq!(err.pipe_err(MyError), Err(MyError("failed")));
+    /// ```
+    fn pipe_err<NewError, Function>(self, f: Function) -> Result<Value, NewError>
+    where
+        Function: FnOnce(Error) -> NewError;
+
+    /// Apply fallible `f` to `value` if `self` is `Ok(value)`, leaving `Err` untouched.
+    ///
+    /// ```
+    /// # use pipe_trait::*;
+    /// fn parse(x: &str) -> Result<i32, &str> {
+    ///     x.parse().map_err(|_| "not a number")
+    /// }
+    /// fn positive(x: i32) -> Result<u32, &'static str> {
+    ///     if x > 0 { Ok(x as u32) } else { Err("not positive") }
+    /// }
+    /// assert_eq!(Ok("12").pipe_and_then(parse).pipe_and_then(positive), Ok(12));
+    /// assert_eq!(Ok("-12").pipe_and_then(parse).pipe_and_then(positive), Err("not positive"));
+    /// ```
+    fn pipe_and_then<Return, Function>(self, f: Function) -> Result<Return, Error>
+    where
+        Function: FnOnce(Value) -> Result<Return, Error>;
+
+    /// Apply fallible `f` to `error` if `self` is `Err(error)`, leaving `Ok` untouched.
+    ///
+    /// `f` is not called when `self` is `Ok`.
+    ///
+    /// ```
+    /// # use pipe_trait::*;
+    /// fn fallback(error: &str) -> Result<i32, usize> {
+    ///     if error == "missing" { Ok(0) } else { Err(error.len()) }
+    /// }
+    /// assert_eq!(Ok(12).pipe_or_else_result(fallback), Ok(12));
+    /// assert_eq!(Err("missing").pipe_or_else_result(fallback), Ok(0));
+    /// assert_eq!(Err("failed").pipe_or_else_result(fallback), Err(6));
+    /// ```
+    fn pipe_or_else_result<NewError, Function>(self, f: Function) -> Result<Value, NewError>
+    where
+        Function: FnOnce(Error) -> Result<Value, NewError>;
+
+    /// Apply `f` to `error` if `self` is `Err(error)`, leaving `Ok` untouched.
+    ///
+    /// This is the same as [`PipeResult::pipe_err`].
+    /// Use [`PipeResult::pipe_map_err_with`] to pass extra context to `f`.
+    ///
+    /// ```
+    /// # use pipe_trait::*;
+    /// #[derive(Debug, PartialEq, Eq)]
+    /// struct MyError(&'static str);
+    /// let err: Result<i32, &str> = Err("failed");
+    /// assert_eq!(err.pipe_map_err(MyError), Err(MyError("failed")));
+    /// ```
+    fn pipe_map_err<NewError, Function>(self, f: Function) -> Result<Value, NewError>
+    where
+        Function: FnOnce(Error) -> NewError;
+
+    /// Apply `f` to `error` and `context` if `self` is `Err(error)`, leaving `Ok` untouched.
+    ///
+    /// ```
+    /// # use pipe_trait::*;
+    /// #[derive(Debug, PartialEq, Eq)]
+    /// struct MyError {
+    ///     path: &'static str,
+    ///     message: &'static str,
+    /// }
+    /// let new_error = |message, path| MyError { path, message };
+    /// let ok: Result<i32, &str> = Ok(12);
+    /// let err: Result<i32, &str> = Err("not found");
+    /// assert_eq!(ok.pipe_map_err_with("config.toml", new_error), Ok(12));
+    /// assert_eq!(
+    ///     err.pipe_map_err_with("config.toml", new_error),
+    ///     Err(MyError {
+    ///         path: "config.toml",
+    ///         message: "not found",
+    ///     }),
+    /// );
+    /// ```
+    fn pipe_map_err_with<Context, NewError, Function>(
+        self,
+        context: Context,
+        f: Function,
+    ) -> Result<Value, NewError>
+    where
+        Function: FnOnce(Error, Context) -> NewError;
+
+    /// Return `value` if `self` is `Ok(value)`, otherwise apply `f` to `error` if `self` is `Err(error)`.
+    ///
+    /// `f` is not called when `self` is `Ok`.
+    ///
+    /// ```
+    /// # use pipe_trait::*;
+    /// let ok: Result<i32, &str> = Ok(12);
+    /// let err: Result<i32, &str> = Err("failed");
+    /// assert_eq!(ok.pipe_recover(|_| 0).pipe(|x| x * 2), 24);
+    /// assert_eq!(err.pipe_recover(|_| 0).pipe(|x| x * 2), 0);
+    /// ```
+    fn pipe_recover<Function>(self, f: Function) -> Value
+    where
+        Function: FnOnce(Error) -> Value;
+
+    /// Call `on_ok` with `&value` if `self` is `Ok(value)`, or `on_err` with `&error`
+    /// if `self` is `Err(error)`, then return `self`.
+    ///
+    /// Only the function matching the variant is called.
+    ///
+    /// ```
+    /// # use pipe_trait::*;
+    /// use core::cell::RefCell;
+    /// let log = RefCell::new(Vec::new());
+    /// let ok: Result<i32, &str> = Ok(12);
+    /// let err: Result<i32, &str> = Err("failed");
+    /// let on_ok = |x: &i32| log.borrow_mut().push(Ok(*x));
+    /// let on_err = |x: &&'static str| log.borrow_mut().push(Err(*x));
+    /// assert_eq!(ok.pipe_inspect_both(on_ok, on_err), Ok(12));
+    /// assert_eq!(log.borrow().as_slice(), [Ok(12)]);
+    /// assert_eq!(err.pipe_inspect_both(on_ok, on_err), Err("failed"));
+    /// assert_eq!(log.borrow().as_slice(), [Ok(12), Err("failed")]);
+    /// ```
+    fn pipe_inspect_both<OnOk, OnErr>(self, on_ok: OnOk, on_err: OnErr) -> Self
+    where
+        OnOk: FnOnce(&Value),
+        OnErr: FnOnce(&Error);
+
+    /// Apply async `f` to `value` if `self` is `Ok(value)`, leaving `Err` untouched,
+    /// then return the future to be awaited.
+    ///
+    /// This method requires the `async` feature.
+    ///
+    /// ```
+    /// # use pipe_trait::*;
+    /// # futures::executor::block_on(async {
+    /// async fn persist(x: i32) -> String {
+    ///     x.to_string()
+    /// }
+    /// let ok: Result<i32, &str> = Ok(12);
+    /// let err: Result<i32, &str> = Err("failed");
+    /// assert_eq!(ok.pipe_ok_async(persist).await, Ok("12".to_string()));
+    /// assert_eq!(err.pipe_ok_async(persist).await, Err("failed"));
+    /// # });
+    /// ```
+    #[cfg(feature = "async")]
+    fn pipe_ok_async<Fut, Function>(self, f: Function) -> PipeOkAsync<Fut, Error>
+    where
+        Function: FnOnce(Value) -> Fut,
+        Fut: Future;
+}
+
+impl<Value, Error> PipeResult<Value, Error> for Result<Value, Error> {
+    #[inline]
+    fn tap_ok<Function>(self, f: Function) -> Self
+    where
+        Function: FnOnce(&Value),
+    {
+        if let Ok(value) = &self {
+            f(value);
+        }
+        self
+    }
+
+    #[inline]
+    fn tap_err<Function>(self, f: Function) -> Self
+    where
+        Function: FnOnce(&Error),
+    {
+        if let Err(error) = &self {
+            f(error);
+        }
+        self
+    }
+
+    #[inline]
+    fn pipe_ok<Return, Function>(self, f: Function) -> Result<Return, Error>
+    where
+        Function: FnOnce(Value) -> Return,
+    {
+        self.map(f)
+    }
+
+    #[inline]
+    fn pipe_err<NewError, Function>(self, f: Function) -> Result<Value, NewError>
+    where
+        Function: FnOnce(Error) -> NewError,
+    {
+        self.map_err(f)
+    }
+
+    #[inline]
+    fn pipe_and_then<Return, Function>(self, f: Function) -> Result<Return, Error>
+    where
+        Function: FnOnce(Value) -> Result<Return, Error>,
+    {
+        self.and_then(f)
+    }
+
+    #[inline]
+    fn pipe_or_else_result<NewError, Function>(self, f: Function) -> Result<Value, NewError>
+    where
+        Function: FnOnce(Error) -> Result<Value, NewError>,
+    {
+        self.or_else(f)
+    }
+
+    #[inline]
+    fn pipe_map_err<NewError, Function>(self, f: Function) -> Result<Value, NewError>
+    where
+        Function: FnOnce(Error) -> NewError,
+    {
+        self.map_err(f)
+    }
+
+    #[inline]
+    fn pipe_map_err_with<Context, NewError, Function>(
+        self,
+        context: Context,
+        f: Function,
+    ) -> Result<Value, NewError>
+    where
+        Function: FnOnce(Error, Context) -> NewError,
+    {
+        self.map_err(|error| f(error, context))
+    }
+
+    #[inline]
+    fn pipe_recover<Function>(self, f: Function) -> Value
+    where
+        Function: FnOnce(Error) -> Value,
+    {
+        self.unwrap_or_else(f)
+    }
+
+    #[inline]
+    fn pipe_inspect_both<OnOk, OnErr>(self, on_ok: OnOk, on_err: OnErr) -> Self
+    where
+        OnOk: FnOnce(&Value),
+        OnErr: FnOnce(&Error),
+    {
+        match &self {
+            Ok(value) => on_ok(value),
+            Err(error) => on_err(error),
+        }
+        self
+    }
+
+    #[cfg(feature = "async")]
+    #[inline]
+    fn pipe_ok_async<Fut, Function>(self, f: Function) -> PipeOkAsync<Fut, Error>
+    where
+        Function: FnOnce(Value) -> Fut,
+        Fut: Future,
+    {
+        match self {
+            Ok(value) => PipeOkAsync::ok(f(value)),
+            Err(error) => PipeOkAsync::err(error),
+        }
+    }
+}
@@ -0,0 +1,35 @@
+use futures::stream::{Map, Stream, StreamExt};
+
+/// All [`Stream`] types implement this trait.
+///
+/// This trait requires the `futures` feature.
+pub trait PipeStream: Stream {
+    /// Apply `f` to every item of `self`.
+    ///
+    /// ```
+    /// # use pipe_trait::*;
+    /// # use futures::{executor::block_on, stream::{self, StreamExt}};
+    /// let numbers = ["1", "2", "3"]
+    ///     .pipe(stream::iter)
+    ///     .pipe_stream_map(str::parse::<i32>)
+    ///     .pipe_stream_map(Result::unwrap)
+    ///     .collect::<Vec<_>>()
+    ///     .pipe(block_on);
+    /// assert_eq!(numbers, [1, 2, 3]);
+    /// ```
+    fn pipe_stream_map<Return, Function>(self, f: Function) -> Map<Self, Function>
+    where
+        Self: Sized,
+        Function: FnMut(Self::Item) -> Return;
+}
+
+impl<X: Stream> PipeStream for X {
+    #[inline]
+    fn pipe_stream_map<Return, Function>(self, f: Function) -> Map<Self, Function>
+    where
+        Self: Sized,
+        Function: FnMut(Self::Item) -> Return,
+    {
+        self.map(f)
+    }
+}
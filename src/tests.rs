@@ -1,5 +1,12 @@
 #![cfg(test)]
 use super::*;
+extern crate std;
+use std::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
 
 #[test]
 fn same_type() {
@@ -103,3 +110,1784 @@ fn pipe_mut_lifetime_bound() {
 
     assert_eq!(actual, expected);
 }
+
+#[test]
+fn pipe_if() {
+    let double = |x| x * 2;
+    assert_eq!(3.pipe_if(true, double), 6);
+    assert_eq!(3.pipe_if(false, double), 3);
+}
+
+#[test]
+fn pipe_if_lazy() {
+    #[derive(Debug, PartialEq, Eq)]
+    struct NonCopy(i32);
+    let value = NonCopy(3).pipe_if(false, |_| panic!("f should not be called"));
+    assert_eq!(value, NonCopy(3));
+    let value = value.pipe_if(true, |NonCopy(x)| NonCopy(x + 1));
+    assert_eq!(value, NonCopy(4));
+}
+
+#[test]
+fn pipe_unless() {
+    let double = |x| x * 2;
+    assert_eq!(3.pipe_unless(false, double), 6);
+    assert_eq!(3.pipe_unless(true, double), 3);
+}
+
+#[test]
+fn pipe_unless_lazy() {
+    let value = 3.pipe_unless(true, |_| panic!("f should not be called"));
+    assert_eq!(value, 3);
+}
+
+#[test]
+fn pipe_if_else() {
+    let is_even = |x: i32| x % 2 == 0;
+    let is_positive = |x: i32| x > 0;
+    let a: bool = 3.pipe_if_else(true, is_even, is_positive);
+    let b: bool = 3.pipe_if_else(false, is_even, is_positive);
+    assert!(!a);
+    assert!(b);
+}
+
+#[test]
+fn pipe_if_else_lazy() {
+    let a = 3.pipe_if_else(true, |x| x * 2, |_| panic!("if_false should not be called"));
+    let b = 3.pipe_if_else(false, |_| panic!("if_true should not be called"), |x| x * 3);
+    assert_eq!((a, b), (6, 9));
+}
+
+#[test]
+fn pipe_when() {
+    #[derive(Debug, PartialEq, Eq)]
+    struct Counter {
+        value: i32,
+        enabled: bool,
+    }
+    fn increase(counter: Counter) -> Counter {
+        Counter {
+            value: counter.value + 1,
+            ..counter
+        }
+    }
+    let enabled = Counter {
+        value: 0,
+        enabled: true,
+    }
+    .pipe_when(|counter| counter.enabled, increase);
+    let disabled = Counter {
+        value: 0,
+        enabled: false,
+    }
+    .pipe_when(|counter| counter.enabled, increase);
+    assert_eq!(enabled.value, 1);
+    assert_eq!(disabled.value, 0);
+}
+
+#[test]
+#[allow(clippy::blacklisted_name)]
+fn pipe_ref_if() {
+    #[derive(Debug, PartialEq, Eq)]
+    struct Foo([i32; 3]);
+    let foo = Foo([1, 2, 3]);
+    let sum = |foo: &Foo| foo.0.iter().sum::<i32>();
+    assert_eq!(foo.pipe_ref_if(true, sum), Some(6));
+    assert_eq!(foo.pipe_ref_if(false, sum), None);
+    assert_eq!(foo, Foo([1, 2, 3]));
+}
+
+#[test]
+#[allow(clippy::blacklisted_name)]
+fn pipe_ref_if_lifetime_bound() {
+    struct Foo(i32);
+    fn f(foo: &'_ Foo) -> &'_ i32 {
+        &foo.0
+    }
+    let foo = Foo(12);
+    assert_eq!(foo.pipe_ref_if(true, f), Some(&12));
+}
+
+#[test]
+fn pipe_mut_if() {
+    #[derive(Debug, PartialEq, Eq)]
+    struct Builder {
+        tls: bool,
+        auth: bool,
+    }
+    fn enable_tls(builder: &mut Builder) {
+        builder.tls = true;
+    }
+    fn enable_auth(builder: &mut Builder) {
+        builder.auth = true;
+    }
+    let mut builder = Builder {
+        tls: false,
+        auth: false,
+    };
+    builder
+        .pipe_mut_if(true, enable_tls)
+        .pipe_mut_if(false, enable_auth)
+        .pipe_mut_if(false, |_| panic!("f should not be called"));
+    assert_eq!(
+        builder,
+        Builder {
+            tls: true,
+            auth: false,
+        },
+    );
+}
+
+#[test]
+fn pipe_as_ref_if() {
+    struct Text(&'static str);
+    impl AsRef<str> for Text {
+        fn as_ref(&self) -> &str {
+            self.0
+        }
+    }
+    let text = Text("abc");
+    let len = |x: &str| x.len();
+    assert_eq!(text.pipe_as_ref_if::<str, _, _>(true, len), Some(3));
+    assert_eq!(text.pipe_as_ref_if::<str, _, _>(false, len), None);
+}
+
+#[test]
+fn pipe_as_ref_if_lazy() {
+    struct Unreachable;
+    impl AsRef<str> for Unreachable {
+        fn as_ref(&self) -> &str {
+            panic!("as_ref should not be called")
+        }
+    }
+    assert_eq!(Unreachable.pipe_as_ref_if(false, |x: &str| x.len()), None);
+}
+
+#[test]
+fn pipe_if_some() {
+    #[derive(Debug, PartialEq, Eq)]
+    struct Request {
+        path: &'static str,
+        token: Option<String>,
+    }
+    fn set_token(request: Request, token: String) -> Request {
+        Request {
+            token: Some(token),
+            ..request
+        }
+    }
+    let request = Request {
+        path: "/",
+        token: None,
+    };
+    let request = request.pipe_if_some(None, set_token);
+    assert_eq!(request.token, None);
+    let request = request.pipe_if_some(Some("abc".to_string()), set_token);
+    assert_eq!(
+        request,
+        Request {
+            path: "/",
+            token: Some("abc".to_string()),
+        },
+    );
+}
+
+#[test]
+fn tap() {
+    #[derive(Debug, PartialEq, Eq)]
+    struct NonCopy(i32);
+    let mut observed = None;
+    let value = NonCopy(12).tap(|x| observed = Some(x.0));
+    assert_eq!(value, NonCopy(12));
+    assert_eq!(observed, Some(12));
+}
+
+#[test]
+fn tap_mut() {
+    let vec = Vec::new()
+        .tap_mut(|x| x.push(1))
+        .tap_mut(|x| x.push(2))
+        .tap_mut(|x| x.push(3));
+    assert_eq!(vec, [1, 2, 3]);
+}
+
+#[test]
+fn tap_ref() {
+    #[derive(Debug, PartialEq, Eq)]
+    struct Config(i32);
+    let config = Config(12);
+    let mut count = 0;
+    let doubled = config
+        .tap_ref(|x| assert_eq!(x.0, 12))
+        .tap_ref(|_| count += 1)
+        .pipe_ref(|x| x.0 * 2);
+    assert_eq!(doubled, 24);
+    assert_eq!(count, 1);
+    assert_eq!(config, Config(12));
+}
+
+#[test]
+fn tap_ref_lifetime_bound() {
+    struct Config(i32);
+    fn get(config: &Config) -> &i32 {
+        &config.0
+    }
+    let config = Config(12);
+    let value: &i32 = config.tap_ref(|_| {}).tap_ref(|_| {}).pipe_ref(get);
+    assert_eq!(value, &12);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+fn tap_dbg() {
+    let mut observed = None;
+    let value = 12.tap_dbg(|x| observed = Some(*x));
+    assert_eq!(value, 12);
+    assert_eq!(observed, Some(12));
+}
+
+#[test]
+#[cfg(not(debug_assertions))]
+fn tap_dbg_release() {
+    let value = 12.tap_dbg(|_| panic!("f should not be called"));
+    assert_eq!(value, 12);
+}
+
+#[test]
+fn tap_deref() {
+    let text = "abc".to_string();
+    let mut observed = 0;
+    let returned: &String = text.tap_deref(|x: &str| observed = x.len());
+    assert_eq!(returned, "abc");
+    assert_eq!(observed, 3);
+}
+
+#[test]
+fn tap_deref_mut() {
+    let mut vec = vec![3, 1, 2];
+    let len = vec
+        .tap_deref_mut(|x: &mut [i32]| x.sort_unstable())
+        .tap_deref_mut(|x: &mut [i32]| x[0] = 10)
+        .pipe_ref(|x| x.len());
+    assert_eq!(len, 3);
+    assert_eq!(vec, [10, 2, 3]);
+}
+
+#[test]
+fn tap_as_ref() {
+    let text = "abc".to_string();
+    let mut observed = String::new();
+    let returned: &String = text.tap_as_ref::<str, _>(|x| observed.push_str(x));
+    assert_eq!(returned, "abc");
+    assert_eq!(observed, "abc");
+}
+
+#[test]
+fn tap_as_mut() {
+    let mut vec = vec![0, 1, 2, 3];
+    vec.tap_as_mut(|x: &mut [i32]| x[0] = 123)
+        .tap_as_mut(|x: &mut [i32]| x.reverse());
+    assert_eq!(vec, [3, 2, 1, 123]);
+}
+
+#[test]
+fn tap_borrow() {
+    let text = "abc".to_string();
+    let mut observed = String::new();
+    let returned: &String = text.tap_borrow(|x: &str| observed.push_str(x));
+    assert_eq!(returned, "abc");
+    assert_eq!(observed, "abc");
+}
+
+#[test]
+fn tap_borrow_mut() {
+    let mut vec = vec![3, 1, 2];
+    let returned: &mut Vec<i32> = vec.tap_borrow_mut(|x: &mut [i32]| x.sort_unstable());
+    returned.push(4);
+    assert_eq!(vec, [1, 2, 3, 4]);
+}
+
+#[test]
+fn tap_if() {
+    let mut observed = None;
+    let value = 12.tap_if(true, |x| observed = Some(*x));
+    assert_eq!(value, 12);
+    assert_eq!(observed, Some(12));
+}
+
+#[test]
+fn tap_if_lazy() {
+    let value = 12.tap_if(false, |_| panic!("f should not be called"));
+    assert_eq!(value, 12);
+}
+
+#[test]
+fn tap_when() {
+    #[derive(Debug, PartialEq, Eq)]
+    struct Order {
+        total: u32,
+    }
+    let mut reviewed = Vec::new();
+    let small = Order { total: 500 }.tap_when(|x| x.total > 1000, |x| reviewed.push(x.total));
+    let large = Order { total: 1500 }.tap_when(|x| x.total > 1000, |x| reviewed.push(x.total));
+    assert_eq!(small, Order { total: 500 });
+    assert_eq!(large, Order { total: 1500 });
+    assert_eq!(reviewed, [1500]);
+}
+
+#[test]
+fn tap_ok() {
+    let mut observed = None;
+    let ok: Result<i32, &str> = Ok(12);
+    let ok = ok.tap_ok(|x| observed = Some(*x));
+    assert_eq!(ok, Ok(12));
+    assert_eq!(observed, Some(12));
+    let err: Result<i32, &str> = Err("failed");
+    let err = err.tap_ok(|_| panic!("f should not be called"));
+    assert_eq!(err, Err("failed"));
+}
+
+#[test]
+fn tap_err() {
+    let mut observed = None;
+    let err: Result<i32, &str> = Err("failed");
+    let err = err.tap_err(|x| observed = Some(*x));
+    assert_eq!(err, Err("failed"));
+    assert_eq!(observed, Some("failed"));
+    let ok: Result<i32, &str> = Ok(12);
+    let ok = ok.tap_err(|_| panic!("f should not be called"));
+    assert_eq!(ok, Ok(12));
+}
+
+#[test]
+fn tap_ok_tap_err_chain() {
+    let mut log = Vec::new();
+    let ok: Result<i32, i32> = Ok(1);
+    let err: Result<i32, i32> = Err(2);
+    let ok = ok.tap_ok(|x| log.push(*x)).tap_err(|x| log.push(-*x));
+    let err = err.tap_ok(|x| log.push(*x)).tap_err(|x| log.push(-*x));
+    assert_eq!((ok, err), (Ok(1), Err(2)));
+    assert_eq!(log, [1, -2]);
+}
+
+#[test]
+fn tap_some() {
+    let mut observed = None;
+    let some = Some(12).tap_some(|x| observed = Some(*x));
+    assert_eq!(some, Some(12));
+    assert_eq!(observed, Some(12));
+    let none = None::<i32>.tap_some(|_| panic!("f should not be called"));
+    assert_eq!(none, None);
+}
+
+#[test]
+fn tap_none() {
+    let mut called = false;
+    let none = None::<i32>.tap_none(|| called = true);
+    assert_eq!(none, None);
+    assert!(called);
+    let some = Some(12).tap_none(|| panic!("f should not be called"));
+    assert_eq!(some, Some(12));
+}
+
+#[test]
+fn try_pipe() {
+    fn parse(x: &str) -> Result<i32, &'static str> {
+        x.parse().map_err(|_| "not a number")
+    }
+    fn positive(x: i32) -> Result<u32, &'static str> {
+        if x > 0 {
+            Ok(x as u32)
+        } else {
+            Err("not positive")
+        }
+    }
+    fn run(x: &str) -> Result<u32, &'static str> {
+        let x = x.try_pipe(parse)?.try_pipe(positive)?;
+        Ok(x * 2)
+    }
+    assert_eq!(run("12"), Ok(24));
+    assert_eq!(run("-12"), Err("not positive"));
+    assert_eq!(run("abc"), Err("not a number"));
+}
+
+#[test]
+fn try_pipe_ref() {
+    #[derive(Debug, PartialEq, Eq)]
+    struct Config {
+        name: &'static str,
+    }
+    fn name(config: &Config) -> Result<&str, &'static str> {
+        if config.name.is_empty() {
+            Err("empty name")
+        } else {
+            Ok(config.name)
+        }
+    }
+    let config = Config { name: "abc" };
+    assert_eq!(config.try_pipe_ref(name), Ok("abc"));
+    assert_eq!(config, Config { name: "abc" });
+    assert_eq!(Config { name: "" }.try_pipe_ref(name), Err("empty name"));
+}
+
+#[test]
+fn try_pipe_mut() {
+    #[derive(Debug, PartialEq, Eq)]
+    struct Document {
+        lines: Vec<&'static str>,
+    }
+    fn apply_patch(document: &mut Document) -> Result<(), &'static str> {
+        document.lines.push("patched");
+        Err("conflict")
+    }
+    let mut document = Document { lines: vec!["a"] };
+    assert_eq!(document.try_pipe_mut(apply_patch), Err("conflict"));
+    assert_eq!(document.lines, ["a", "patched"]);
+}
+
+#[test]
+fn try_pipe_as_ref() {
+    use core::num::ParseIntError;
+    fn parse_int(x: &str) -> Result<i32, ParseIntError> {
+        x.parse()
+    }
+    let valid = "123".to_string();
+    let invalid = "abc".to_string();
+    assert_eq!(valid.try_pipe_as_ref::<str, _, _, _>(parse_int), Ok(123));
+    assert!(invalid.try_pipe_as_ref::<str, _, _, _>(parse_int).is_err());
+}
+
+#[test]
+fn try_pipe_deref() {
+    use core::num::ParseIntError;
+    fn parse_int(x: &str) -> Result<i32, ParseIntError> {
+        x.parse()
+    }
+    let valid = "123".to_string();
+    let invalid = "abc".to_string();
+    assert_eq!(valid.try_pipe_deref(parse_int), Ok(123));
+    assert!(invalid.try_pipe_deref(parse_int).is_err());
+}
+
+#[test]
+fn try_pipe_borrow() {
+    fn lookup(key: &str) -> Result<i32, &str> {
+        match key {
+            "one" => Ok(1),
+            "two" => Ok(2),
+            _ => Err(key),
+        }
+    }
+    let valid = "two".to_string();
+    let invalid = "three".to_string();
+    assert_eq!(valid.try_pipe_borrow(lookup), Ok(2));
+    assert_eq!(invalid.try_pipe_borrow(lookup), Err("three"));
+}
+
+#[test]
+fn try_pipe_as_mut() {
+    fn increase_all(target: &mut [u8]) -> Result<(), &'static str> {
+        for x in target {
+            *x = x.checked_add(1).ok_or("overflow")?;
+        }
+        Ok(())
+    }
+    let mut vec: Vec<u8> = vec![1, 255, 3];
+    assert_eq!(vec.try_pipe_as_mut(increase_all), Err("overflow"));
+    assert_eq!(vec, [2, 255, 3]);
+}
+
+#[test]
+fn try_pipe_deref_mut() {
+    use std::boxed::Box;
+    fn decode(target: &mut [u8]) -> Result<usize, &'static str> {
+        let source = b"ab";
+        if target.len() < source.len() {
+            return Err("buffer too small");
+        }
+        target[..source.len()].copy_from_slice(source);
+        Ok(source.len())
+    }
+    let mut large: Box<[u8]> = Box::new([0; 3]);
+    assert_eq!(large.try_pipe_deref_mut(decode), Ok(2));
+    assert_eq!(*large, *b"ab\0");
+    let mut small: Box<[u8]> = Box::new([0; 1]);
+    assert_eq!(small.try_pipe_deref_mut(decode), Err("buffer too small"));
+    assert_eq!(*small, [0]);
+}
+
+#[test]
+fn try_pipe_borrow_mut() {
+    fn sort_unique(target: &mut [i32]) -> Result<(), &'static str> {
+        target.sort_unstable();
+        if target.windows(2).any(|x| x[0] == x[1]) {
+            return Err("duplicated items");
+        }
+        Ok(())
+    }
+    let mut unique = vec![3, 1, 2];
+    assert_eq!(unique.try_pipe_borrow_mut(sort_unique), Ok(()));
+    assert_eq!(unique, [1, 2, 3]);
+    let mut duplicated = vec![3, 1, 3];
+    assert_eq!(
+        duplicated.try_pipe_borrow_mut(sort_unique),
+        Err("duplicated items"),
+    );
+    assert_eq!(duplicated, [1, 3, 3]);
+}
+
+#[test]
+fn pipe_ok() {
+    #[derive(Debug, PartialEq, Eq)]
+    struct Foo(i32);
+    let ok: Result<i32, &str> = Ok(3);
+    let err: Result<i32, &str> = Err("failed");
+    assert_eq!(ok.pipe_ok(|x| x + 1).pipe_ok(Foo), Ok(Foo(4)));
+    assert_eq!(
+        err.pipe_ok(|_| panic!("f should not be called"))
+            .pipe_ok(Foo),
+        Err("failed"),
+    );
+}
+
+#[test]
+fn pipe_err() {
+    #[derive(Debug, PartialEq, Eq)]
+    enum MyError {
+        Message(&'static str),
+    }
+    let ok: Result<i32, &'static str> = Ok(3);
+    let err: Result<i32, &'static str> = Err("failed");
+    let ok: Result<i32, MyError> = ok.pipe_err(|_| panic!("f should not be called"));
+    let err: Result<i32, MyError> = err.pipe_err(MyError::Message);
+    assert_eq!(ok.pipe_ok(|x| x * 2), Ok(6));
+    assert_eq!(err, Err(MyError::Message("failed")));
+}
+
+#[test]
+fn pipe_some() {
+    #[derive(Debug, PartialEq, Eq)]
+    struct Foo(i32);
+    assert_eq!(Some(3).pipe_some(|x| x + 1).pipe_some(Foo), Some(Foo(4)));
+    assert_eq!(
+        None.pipe_some(|_: i32| -> i32 { panic!("f should not be called") })
+            .pipe_some(Foo),
+        None,
+    );
+}
+
+#[test]
+fn pipe_none() {
+    assert_eq!(None.pipe_none(|| Some(3)), Some(3));
+    assert_eq!(None::<i32>.pipe_none(|| None), None);
+    assert_eq!(
+        Some(12).pipe_none(|| panic!("f should not be called")),
+        Some(12)
+    );
+}
+
+#[test]
+fn pipe_and_then() {
+    fn half(x: i32) -> Result<i32, i32> {
+        if x % 2 == 0 {
+            Ok(x / 2)
+        } else {
+            Err(x)
+        }
+    }
+    let ok: Result<i32, i32> = Ok(8);
+    let err: Result<i32, i32> = Ok(6);
+    assert_eq!(ok.pipe_and_then(half).pipe_and_then(half), Ok(2));
+    assert_eq!(
+        err.pipe_and_then(half)
+            .pipe_and_then(half)
+            .pipe_and_then(|_| panic!("f should not be called")),
+        Err::<i32, _>(3),
+    );
+}
+
+#[test]
+fn pipe_or_else_result() {
+    let ok: Result<i32, &str> = Ok(12);
+    let ok: Result<i32, usize> = ok.pipe_or_else_result(|_| panic!("f should not be called"));
+    assert_eq!(ok, Ok(12));
+    let recovered: Result<i32, &str> = Err("missing");
+    let recovered: Result<i32, usize> = recovered.pipe_or_else_result(|_| Ok(0));
+    assert_eq!(recovered, Ok(0));
+    let failed: Result<i32, &str> = Err("failed");
+    let failed: Result<i32, usize> = failed.pipe_or_else_result(|x| Err(x.len()));
+    assert_eq!(failed, Err(6));
+}
+
+#[test]
+fn pipe_map_err() {
+    let ok: Result<i32, &str> = Ok(12);
+    let err: Result<i32, &str> = Err("failed");
+    assert_eq!(
+        ok.pipe_map_err(|_| -> usize { panic!("f should not be called") }),
+        Ok(12)
+    );
+    assert_eq!(err.pipe_map_err(str::len), Err(6));
+}
+
+#[test]
+fn pipe_map_err_with() {
+    #[derive(Debug, PartialEq, Eq)]
+    struct ContextError {
+        path: &'static str,
+        message: &'static str,
+    }
+    let new_error = |message, path| ContextError { path, message };
+    let ok: Result<i32, &str> = Ok(12);
+    let err: Result<i32, &str> = Err("not found");
+    assert_eq!(ok.pipe_map_err_with("a.txt", new_error), Ok(12));
+    assert_eq!(
+        err.pipe_map_err_with("a.txt", new_error),
+        Err(ContextError {
+            path: "a.txt",
+            message: "not found",
+        }),
+    );
+}
+
+#[test]
+fn pipe_ok_or() {
+    assert_eq!(Some(12).pipe_ok_or("missing"), Ok(12));
+    assert_eq!(None::<i32>.pipe_ok_or("missing"), Err("missing"));
+}
+
+#[test]
+fn pipe_ok_or_else() {
+    let some: Result<i32, &str> = Some(12).pipe_ok_or_else(|| panic!("f should not be called"));
+    assert_eq!(some, Ok(12));
+    assert_eq!(None::<i32>.pipe_ok_or_else(|| "missing"), Err("missing"));
+}
+
+#[test]
+fn pipe_unwrap_or_pipe() {
+    #[derive(Debug, Default, PartialEq, Eq)]
+    struct Config {
+        verbose: bool,
+    }
+    let apply = |config: Config| config.verbose;
+    let some = Some(Config { verbose: true });
+    assert!(some.pipe_unwrap_or_pipe(Config::default(), apply));
+    assert!(!None.pipe_unwrap_or_pipe(Config::default(), apply));
+}
+
+#[test]
+fn pipe_unwrap_or_else_pipe() {
+    let double = |x| x * 2;
+    assert_eq!(
+        Some(12).pipe_unwrap_or_else_pipe(|| panic!("default should not be called"), double),
+        24,
+    );
+    assert_eq!(None.pipe_unwrap_or_else_pipe(|| 3, double), 6);
+}
+
+#[test]
+fn pipe_filter() {
+    #[derive(Debug, PartialEq, Eq)]
+    struct NonCopy(i32);
+    let is_even = |x: &NonCopy| x.0 % 2 == 0;
+    assert_eq!(Some(NonCopy(2)).pipe_filter(is_even), Some(NonCopy(2)));
+    assert_eq!(Some(NonCopy(3)).pipe_filter(is_even), None);
+    assert_eq!(
+        None.pipe_filter(|_: &NonCopy| panic!("predicate should not be called")),
+        None,
+    );
+}
+
+#[test]
+fn pipe_recover() {
+    let ok: Result<i32, &str> = Ok(12);
+    let err: Result<i32, &str> = Err("failed");
+    assert_eq!(ok.pipe_recover(|_| panic!("f should not be called")), 12);
+    assert_eq!(err.pipe_recover(|x| x.len() as i32), 6);
+}
+
+#[test]
+fn pipe_transpose() {
+    let some_ok: Option<Result<i32, &str>> = Some(Ok(12));
+    let some_err: Option<Result<i32, &str>> = Some(Err("failed"));
+    let none: Option<Result<i32, &str>> = None;
+    assert_eq!(some_ok.pipe_transpose(), Ok(Some(12)));
+    assert_eq!(some_err.pipe_transpose(), Err("failed"));
+    assert_eq!(none.pipe_transpose(), Ok(None));
+    assert_eq!(some_ok.pipe_transpose().pipe_transpose(), some_ok);
+    assert_eq!(some_err.pipe_transpose().pipe_transpose(), some_err);
+    assert_eq!(none.pipe_transpose().pipe_transpose(), none);
+}
+
+#[test]
+fn pipe_flatten_result() {
+    type Nested = Result<Result<i32, &'static str>, &'static str>;
+    let ok: Nested = Ok(Ok(12));
+    let inner_err: Nested = Ok(Err("inner"));
+    let outer_err: Nested = Err("outer");
+    assert_eq!(ok.pipe_flatten_result(), Ok(12));
+    assert_eq!(inner_err.pipe_flatten_result(), Err("inner"));
+    assert_eq!(outer_err.pipe_flatten_result(), Err("outer"));
+}
+
+#[test]
+fn pipe_flatten_result_after_pipe_ok() {
+    fn sub_op(x: i32) -> Result<i32, &'static str> {
+        if x > 0 {
+            Ok(x * 2)
+        } else {
+            Err("not positive")
+        }
+    }
+    let ok: Result<i32, &str> = Ok(3);
+    let err: Result<i32, &str> = Ok(-3);
+    assert_eq!(ok.pipe_ok(sub_op).pipe_flatten_result(), Ok(6));
+    assert_eq!(
+        err.pipe_ok(sub_op).pipe_flatten_result(),
+        Err("not positive")
+    );
+}
+
+#[test]
+fn pipe_inspect_both() {
+    let mut observed = None;
+    let ok: Result<i32, &str> = Ok(12);
+    let ok = ok.pipe_inspect_both(
+        |x| observed = Some(*x),
+        |_| panic!("on_err should not be called"),
+    );
+    assert_eq!(ok, Ok(12));
+    assert_eq!(observed, Some(12));
+    let err: Result<i32, &str> = Err("failed");
+    let err = err.pipe_inspect_both(
+        |_| panic!("on_ok should not be called"),
+        |x| observed = Some(x.len() as i32),
+    );
+    assert_eq!(err, Err("failed"));
+    assert_eq!(observed, Some(6));
+}
+
+#[test]
+fn pipe_xor() {
+    assert_eq!(Some(1).pipe_xor(Some(2)), None);
+    assert_eq!(Some(1).pipe_xor(None), Some(1));
+    assert_eq!(None.pipe_xor(Some(2)), Some(2));
+    assert_eq!(None::<i32>.pipe_xor(None), None);
+}
+
+#[test]
+fn pipe_get_or_insert_with() {
+    let mut empty: Option<i32> = None;
+    *empty.pipe_get_or_insert_with(|| 3) *= 2;
+    assert_eq!(empty, Some(6));
+    let mut populated = Some(12);
+    let value = populated.pipe_get_or_insert_with(|| panic!("f should not be called"));
+    assert_eq!(*value, 12);
+}
+
+#[test]
+fn pipe_or_default() {
+    #[derive(Debug, PartialEq, Eq)]
+    struct Settings {
+        retries: u32,
+    }
+    impl Default for Settings {
+        fn default() -> Self {
+            Settings { retries: 3 }
+        }
+    }
+    let some = Some(Settings { retries: 5 });
+    assert_eq!(some.pipe_or_default(), Settings { retries: 5 });
+    assert_eq!(None::<Settings>.pipe_or_default(), Settings { retries: 3 });
+}
+
+#[test]
+#[cfg(feature = "async")]
+fn pipe_async() {
+    use futures::{executor::block_on, future::ready};
+    async fn double(x: i32) -> i32 {
+        x * 2
+    }
+    let value = block_on(async { 3.pipe_async(double).await.pipe_async(ready).await });
+    assert_eq!(value, 6);
+}
+
+#[test]
+#[cfg(feature = "async")]
+fn pipe_ref_async() {
+    use futures::executor::block_on;
+    #[derive(Debug, PartialEq, Eq)]
+    struct Client {
+        host: &'static str,
+    }
+    async fn ping(client: &Client) -> &'static str {
+        client.host
+    }
+    let client = Client { host: "localhost" };
+    let host = block_on(client.pipe_ref_async(ping));
+    assert_eq!(host, "localhost");
+    assert_eq!(client, Client { host: "localhost" });
+}
+
+#[test]
+#[cfg(feature = "async")]
+fn pipe_mut_async() {
+    use futures::{executor::block_on, future::ready};
+    async fn refill(buffer: &mut Vec<u8>) {
+        let chunk = ready(*b"abc").await;
+        buffer.extend_from_slice(&chunk);
+    }
+    let mut buffer = vec![0];
+    block_on(async {
+        buffer.pipe_mut_async(refill).await;
+        buffer.pipe_mut_async(refill).await;
+    });
+    assert_eq!(buffer, b"\0abcabc");
+}
+
+#[test]
+#[cfg(feature = "async")]
+fn pipe_as_ref_async() {
+    use futures::executor::block_on;
+    async fn uppercase(x: &str) -> String {
+        x.to_uppercase()
+    }
+    let text = "abc".to_string();
+    let upper = block_on(text.pipe_as_ref_async::<str, _, _>(uppercase));
+    assert_eq!(upper, "ABC");
+    assert_eq!(text, "abc");
+}
+
+#[test]
+#[cfg(feature = "async")]
+fn pipe_deref_async() {
+    use futures::executor::block_on;
+    async fn first_char(x: &str) -> Option<char> {
+        x.chars().next()
+    }
+    let text = "abc".to_string();
+    assert_eq!(block_on(text.pipe_deref_async(first_char)), Some('a'));
+    assert_eq!(text, "abc");
+}
+
+#[test]
+#[cfg(feature = "async")]
+fn pipe_borrow_async() {
+    use futures::executor::block_on;
+    use std::boxed::Box;
+    #[derive(Debug, PartialEq, Eq)]
+    struct Data(i32);
+    async fn get(data: &Data) -> i32 {
+        data.0
+    }
+    let boxed = Box::new(Data(12));
+    assert_eq!(block_on(boxed.pipe_borrow_async(get)), 12);
+    assert_eq!(boxed, Box::new(Data(12)));
+}
+
+#[test]
+#[cfg(all(feature = "async", feature = "alloc"))]
+fn tap_async() {
+    use core::sync::atomic::{AtomicI32, Ordering};
+    use futures::executor::block_on;
+    #[derive(Debug, PartialEq, Eq)]
+    struct Response(i32);
+    static TOTAL: AtomicI32 = AtomicI32::new(0);
+    async fn record(response: &Response) {
+        futures::future::ready(()).await;
+        TOTAL.fetch_add(response.0, Ordering::SeqCst);
+    }
+    let future = Response(12).tap_async(record);
+    assert_eq!(TOTAL.load(Ordering::SeqCst), 0);
+    let response = block_on(async { future.await.tap_async(record).await });
+    assert_eq!(response, Response(12));
+    assert_eq!(TOTAL.load(Ordering::SeqCst), 24);
+}
+
+#[test]
+#[cfg(feature = "async")]
+fn try_pipe_async() {
+    use futures::{executor::block_on, future::ready};
+    async fn parse(x: &str) -> Result<i32, &'static str> {
+        x.parse().map_err(|_| "not a number")
+    }
+    fn positive(x: i32) -> futures::future::Ready<Result<u32, &'static str>> {
+        ready(if x > 0 {
+            Ok(x as u32)
+        } else {
+            Err("not positive")
+        })
+    }
+    async fn run(x: &str) -> Result<u32, &'static str> {
+        let x = x
+            .try_pipe_async(parse)
+            .await?
+            .try_pipe_async(positive)
+            .await?;
+        Ok(x * 2)
+    }
+    assert_eq!(block_on(run("12")), Ok(24));
+    assert_eq!(block_on(run("-12")), Err("not positive"));
+    assert_eq!(block_on(run("abc")), Err("not a number"));
+}
+
+#[test]
+#[cfg(feature = "async")]
+fn pipe_ok_async() {
+    use futures::executor::block_on;
+    async fn double(x: i32) -> i32 {
+        x * 2
+    }
+    let ok: Result<i32, &str> = Ok(12);
+    let err: Result<i32, &str> = Err("failed");
+    assert_eq!(block_on(ok.pipe_ok_async(double)), Ok(24));
+    assert_eq!(
+        block_on(err.pipe_ok_async(|_| async { panic!("f should not be called") })),
+        Err::<(), _>("failed"),
+    );
+}
+
+#[cfg(feature = "tokio")]
+fn tokio_runtime() -> tokio::runtime::Runtime {
+    tokio::runtime::Builder::new_current_thread()
+        .enable_time()
+        .build()
+        .expect("build tokio runtime")
+}
+
+#[test]
+#[cfg(feature = "tokio")]
+fn pipe_timeout() {
+    use core::time::Duration;
+    async fn slow(x: i32) -> i32 {
+        tokio::time::sleep(Duration::from_secs(5)).await;
+        x
+    }
+    async fn fast(x: i32) -> i32 {
+        x
+    }
+    let runtime = tokio_runtime();
+    let completed = runtime.block_on(async { 12.pipe_timeout(Duration::from_secs(5), fast).await });
+    assert_eq!(completed, Ok(12));
+    let elapsed =
+        runtime.block_on(async { 12.pipe_timeout(Duration::from_millis(10), slow).await });
+    assert!(elapsed.is_err());
+}
+
+#[test]
+#[cfg(feature = "tokio")]
+fn pipe_spawn() {
+    let runtime = tokio_runtime();
+    let text = runtime
+        .block_on(async { vec![1, 2, 3].pipe_spawn(|x| x.len().to_string()).await })
+        .expect("join spawned task");
+    assert_eq!(text, "3");
+}
+
+#[test]
+#[cfg(feature = "futures")]
+fn pipe_stream_map() {
+    use futures::{executor::block_on, stream, StreamExt};
+    let doubled = stream::iter(vec![1, 2, 3])
+        .pipe_stream_map(|x| x * 2)
+        .collect::<Vec<_>>()
+        .pipe(block_on);
+    assert_eq!(doubled, [2, 4, 6]);
+}
+
+#[test]
+#[cfg(feature = "async")]
+fn pipe_join_await() {
+    use core::cell::Cell;
+    use futures::{executor::block_on, future::ready};
+    let called = Cell::new(false);
+    let future = "x".pipe_join_await(ready(3), |(text, count)| {
+        called.set(true);
+        text.repeat(count)
+    });
+    assert!(!called.get());
+    assert_eq!(block_on(future), "xxx");
+    assert!(called.get());
+}
+
+#[test]
+#[cfg(feature = "async")]
+fn pipe_join_await_lazy_other() {
+    use futures::{executor::block_on, future::lazy};
+    let mut polled = false;
+    let other = lazy(|_| {
+        polled = true;
+        12
+    });
+    let pair = (1, 2).pipe_join_await(other, |pair| pair).pipe(block_on);
+    assert_eq!(pair, ((1, 2), 12));
+    assert!(polled);
+}
+
+#[test]
+#[cfg(feature = "executor")]
+fn pipe_block_on() {
+    async fn double(x: i32) -> i32 {
+        x * 2
+    }
+    assert_eq!(21.pipe_block_on(double), 42);
+    assert_eq!(3.pipe_block_on(|x| async move { x + 1 }), 4);
+}
+
+#[test]
+#[cfg(feature = "futures")]
+fn pipe_now_or_never() {
+    async fn double(x: i32) -> i32 {
+        x * 2
+    }
+    assert_eq!(21.pipe_now_or_never(double), Some(42));
+    let pending = 21.pipe_now_or_never(|_| futures::future::pending::<i32>());
+    assert_eq!(pending, None);
+}
+
+#[test]
+fn pipe_macro() {
+    fn inc(x: i32) -> i32 {
+        x + 1
+    }
+    fn double(x: i32) -> i32 {
+        x * 2
+    }
+    assert_eq!(pipe!(3), 3);
+    assert_eq!(pipe!(3 => inc => double), double(inc(3)));
+    assert_eq!(pipe!(3 => |x| x * x => inc), 10);
+    assert_eq!(pipe!(3 => inc => .pow(2) => .to_string()), "16");
+}
+
+#[test]
+fn pipe_macro_mixed_with_pipe() {
+    fn inc(x: i32) -> i32 {
+        x + 1
+    }
+    let x = pipe!(3.pipe(inc) => |x| x * 10).pipe(inc);
+    assert_eq!(x, 41);
+}
+
+#[test]
+fn pipeline_macro() {
+    fn inc(x: i32) -> i32 {
+        x + 1
+    }
+    let single = pipeline!(inc);
+    assert_eq!(single(1), 2);
+    let combined = pipeline!(inc, |x| x * 2, inc, |x: i32| x.to_string());
+    let actual: Vec<_> = (0..4).map(&combined).collect();
+    assert_eq!(actual, ["3", "5", "7", "9"]);
+    assert_eq!(4.pipe(combined), "11");
+}
+
+#[test]
+fn pipeline_macro_owned_input() {
+    let shout = pipeline!(|x: String| x + "!", |x: String| x.to_uppercase());
+    let words = ["a".to_string(), "bc".to_string()];
+    let actual: Vec<_> = words.iter().cloned().map(&shout).collect();
+    assert_eq!(actual, ["A!", "BC!"]);
+    let count = pipeline!(String::into_bytes, |x: Vec<u8>| x.len());
+    assert_eq!(count("abc".to_string()), 3);
+    assert_eq!(count(String::new()), 0);
+}
+
+#[test]
+fn pipeline_macro_annotated_input() {
+    let count = pipeline!(|x: &str| x.trim(), str::len);
+    let first = " a ".to_string();
+    assert_eq!(count(&first), 1);
+    drop(first);
+    let second = " bc ".to_string();
+    assert_eq!(count(&second), 2);
+    let parse = pipeline!(|x: &str| -> i32 { x.trim().parse().unwrap() });
+    let third = " 12 ".to_string();
+    assert_eq!(parse(&third), 12);
+    drop(third);
+    assert_eq!(parse("34"), 34);
+}
+
+#[test]
+fn compose_macro() {
+    let inc = |x: i32| x + 1;
+    let double = |x: i32| x * 2;
+    let to_string = |x: i32| x.to_string();
+    let single = compose!(inc);
+    assert_eq!(single(1), 2);
+    let composed = compose!(to_string, double, inc);
+    let actual: Vec<_> = (0..3).map(&composed).collect();
+    assert_eq!(actual, ["2", "4", "6"]);
+    assert_eq!(compose!(inc, double)(5), pipeline!(double, inc)(5));
+    assert_ne!(compose!(inc, double)(5), pipeline!(inc, double)(5));
+}
+
+#[test]
+fn tap_macro() {
+    let mut log = Vec::new();
+    let x = tap!(vec![1, 2, 3];
+        |x| log.push(x.len() as i32);
+        |x| log.push(x.iter().sum());
+        |x| log.push(x[0]);
+    );
+    assert_eq!(x, [1, 2, 3]);
+    assert_eq!(log, [3, 6, 1]);
+    assert_eq!(tap!("unchanged"), "unchanged");
+}
+
+#[test]
+fn try_pipe_macro() {
+    fn parse(text: &str) -> Result<i32, String> {
+        text.parse().map_err(|_| format!("not a number: {}", text))
+    }
+    fn validate(x: i32) -> Result<i32, String> {
+        if x % 2 == 0 {
+            Ok(x)
+        } else {
+            Err(format!("odd: {}", x))
+        }
+    }
+    fn build(x: i32) -> Result<String, String> {
+        Ok(x.to_string().repeat(2))
+    }
+    fn run(text: &str) -> Result<String, String> {
+        Ok(try_pipe!(text => parse => validate => build))
+    }
+    assert_eq!(run("12"), Ok("1212".to_string()));
+    assert_eq!(run("13"), Err("odd: 13".to_string()));
+    fn unsigned(text: &str) -> Option<&str> {
+        Some(try_pipe!(text => .strip_prefix('+') => .get(..2)))
+    }
+    assert_eq!(unsigned("+123"), Some("12"));
+    assert_eq!(unsigned("123"), None);
+}
+
+#[test]
+fn try_pipe_macro_field() {
+    struct Config {
+        port: Option<u16>,
+    }
+    fn doubled(config: Config) -> Option<u16> {
+        Some(try_pipe!(config => .port => |x: u16| x.checked_mul(2)))
+    }
+    assert_eq!(doubled(Config { port: Some(80) }), Some(160));
+    assert_eq!(doubled(Config { port: Some(40000) }), None);
+    assert_eq!(doubled(Config { port: None }), None);
+    fn first(pair: (Result<i32, String>, &str)) -> Result<i32, String> {
+        Ok(try_pipe!(pair => .0))
+    }
+    assert_eq!(first((Ok(12), "ignored")), Ok(12));
+    assert_eq!(
+        first((Err("failed".to_string()), "ignored")),
+        Err("failed".to_string())
+    );
+}
+
+#[test]
+fn try_pipe_macro_short_circuit() {
+    fn run(x: Option<i32>) -> Option<i32> {
+        let x = try_pipe!(x => |_| None::<i32> => |_| -> Option<i32> { panic!("f should not be called") });
+        Some(x)
+    }
+    assert_eq!(run(Some(12)), None);
+}
+
+#[test]
+fn pipe_match_macro() {
+    enum Shape {
+        Circle(u32),
+        Rectangle { width: u32, height: u32 },
+        Point,
+    }
+    let area = |shape: Shape| {
+        pipe_match!(shape {
+            Shape::Circle(radius) => 3 * radius * radius,
+            Shape::Rectangle { width, height } if width == height => width * width,
+            Shape::Rectangle { width, height } => width * height,
+            _ => 0,
+        })
+    };
+    assert_eq!(Shape::Circle(2).pipe(area), 12);
+    assert_eq!(
+        Shape::Rectangle {
+            width: 3,
+            height: 3
+        }
+        .pipe(area),
+        9
+    );
+    assert_eq!(
+        Shape::Rectangle {
+            width: 2,
+            height: 5
+        }
+        .pipe(area),
+        10
+    );
+    assert_eq!(Shape::Point.pipe(area), 0);
+}
+
+#[test]
+fn pipe_match_macro_long_value() {
+    let values = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16];
+    let sign = pipe_match!({ values.iter().map(|x| x * 2).filter(|x| x % 3 == 0).sum::<i32>() - 90 } {
+        0 => "zero",
+        x if x < 0 => "negative",
+        _ => "positive",
+    });
+    assert_eq!(sign, "zero");
+}
+
+#[test]
+fn pipe_let_macro() {
+    let x = pipe_let!(vec![3, 1, 2]
+        => |mut x: Vec<i32>| { x.sort_unstable(); x }
+        => let sorted; sorted.len()
+        => |len| sorted[len - 1] - sorted[0]
+        => |range| (range, sorted.clone())
+    );
+    assert_eq!(x, (2, vec![1, 2, 3]));
+}
+
+#[test]
+fn pipe_let_macro_without_let() {
+    assert_eq!(pipe_let!(3), 3);
+    assert_eq!(pipe_let!(3 => |x: i32| x + 1 => .to_string()), "4");
+}
+
+#[test]
+fn pipe_field_macro() {
+    struct Person {
+        name: String,
+        age: u32,
+    }
+    let people = vec![
+        Person {
+            name: "alice".to_string(),
+            age: 30,
+        },
+        Person {
+            name: "bob".to_string(),
+            age: 25,
+        },
+    ];
+    let names: Vec<_> = people
+        .iter()
+        .map(pipe_field!(.name.to_uppercase()))
+        .collect();
+    assert_eq!(names, ["ALICE", "BOB"]);
+    let ages: Vec<_> = people.iter().map(pipe_field!(.age)).collect();
+    assert_eq!(ages, [30, 25]);
+}
+
+#[test]
+fn pipe_macro_field_access() {
+    struct Wrapper {
+        inner: (&'static str, u32),
+    }
+    let wrapper = Wrapper {
+        inner: ("hello", 3),
+    };
+    let x = pipe!(wrapper => .inner => .0 => .to_uppercase() => |x: String| x.len());
+    assert_eq!(x, 5);
+    let y = pipe_let!(("a", 2) => let pair; pair => .0 => .repeat(pair.1));
+    assert_eq!(y, "aa");
+}
+
+#[test]
+fn compose_once() {
+    fn parse(text: &str) -> i32 {
+        text.parse().unwrap()
+    }
+    let composed = compose(parse, |x| x * 2);
+    assert_eq!(composed("21"), 42);
+    let composed = compose(|x: i32| x.to_string(), |x| x + "!");
+    assert_eq!(12.pipe(composed), "12!");
+}
+
+#[test]
+fn compose_once_lazy() {
+    let _ = compose(
+        |_: i32| -> i32 { panic!("f should not be called") },
+        |_: i32| -> i32 { panic!("g should not be called") },
+    );
+}
+
+#[test]
+fn compose_reusable() {
+    let describe = compose_fn(|x: i32| x * 2, |x: i32| x.to_string());
+    let actual: Vec<_> = (1..4).map(&describe).collect();
+    assert_eq!(actual, ["2", "4", "6"]);
+    assert_eq!(describe(10), "20");
+    let mut log = Vec::new();
+    let mut record = compose_fn_mut(|x: i32| x + 1, |x: i32| log.push(x));
+    record(1);
+    record(2);
+    drop(record);
+    assert_eq!(log, [2, 3]);
+}
+
+#[test]
+fn compose3_reusable() {
+    let once = compose3(|x: i32| x + 1, |x| x * 2, |x: i32| x.to_string());
+    assert_eq!(once(1), "4");
+    let reusable = compose3_fn(|x: i32| x + 1, |x| x * 2, |x: i32| x.to_string());
+    assert_eq!(reusable(1), "4");
+    assert_eq!(reusable(2), "6");
+    let mut total = 0;
+    let mut accumulate = compose3_fn_mut(
+        |x: i32| x + 1,
+        |x| x * 2,
+        |x| {
+            total += x;
+            total
+        },
+    );
+    assert_eq!(accumulate(1), 4);
+    assert_eq!(accumulate(2), 10);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn pipeline() {
+    let pipeline = Pipeline::new(|x: &str| x.trim())
+        .then(|x| x.parse::<i32>().unwrap())
+        .then(|x| x * 2);
+    assert_eq!(pipeline.apply(" 21 "), 42);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn pipeline_from_data() {
+    let offsets = [1, 2, 3];
+    let pipeline = offsets
+        .iter()
+        .map(|&offset| move |x: i32| x * 2 + offset)
+        .fold(Pipeline::identity(), Pipeline::then);
+    assert_eq!(pipeline.apply(0), 11);
+    let lazy = Pipeline::new(|_: i32| -> i32 { panic!("stage should not be called") });
+    let _ = lazy.then(|x| x + 1);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn pipeline_borrowed() {
+    let text = " a, bb ,ccc ".to_string();
+    let separator = ',';
+    let pipeline = Pipeline::new(|x: &String| x.trim())
+        .then(|x| x.split(separator))
+        .then(|parts| parts.map(str::trim).collect::<Vec<&str>>());
+    assert_eq!(pipeline.apply(&text), ["a", "bb", "ccc"]);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn result_pipeline() {
+    let pipeline = || {
+        ResultPipeline::new(|x: &str| x.parse::<i32>().map_err(|_| "not a number"))
+            .and_then(|x| if x % 2 == 0 { Ok(x) } else { Err("odd") })
+            .and_then(|x| Ok(x.to_string()))
+    };
+    assert_eq!(pipeline().apply("12"), Ok("12".to_string()));
+    assert_eq!(pipeline().apply("13"), Err("odd"));
+    assert_eq!(pipeline().apply("abc"), Err("not a number"));
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn result_pipeline_short_circuit() {
+    let pipeline = ResultPipeline::identity()
+        .and_then(|x: i32| if x > 100 { Ok(x) } else { Err("too small") })
+        .and_then(|_| -> Result<i32, &str> { panic!("stage should not be called") });
+    assert_eq!(pipeline.apply(12), Err("too small"));
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn result_pipeline_borrowed() {
+    let text = "12,abc".to_string();
+    let limit = 100;
+    let pipeline = || {
+        ResultPipeline::identity()
+            .and_then(|x: &str| x.parse::<i32>().map_err(|_| x))
+            .and_then(|x| if x < limit { Ok(x) } else { Err("too large") })
+    };
+    let (left, right) = text.split_at(2);
+    assert_eq!(pipeline().apply(left), Ok(12));
+    assert_eq!(pipeline().apply(&right[1..]), Err("abc"));
+}
+
+#[test]
+#[cfg(all(feature = "alloc", feature = "async"))]
+fn async_pipeline() {
+    use futures::executor::block_on;
+    async fn double(x: i32) -> i32 {
+        x * 2
+    }
+    let pipeline = AsyncPipeline::identity()
+        .then_async(double)
+        .then_async(|x| async move { x.to_string() });
+    assert_eq!(block_on(pipeline.apply(21)), "42");
+}
+
+#[test]
+#[cfg(all(feature = "alloc", feature = "async"))]
+fn async_pipeline_send() {
+    use futures::executor::block_on;
+    fn assert_send<Value: Send>(value: Value) -> Value {
+        value
+    }
+    let suffix = "!".to_string();
+    let pipeline = AsyncPipeline::new(|x: &str| async move { x.trim() })
+        .then_async(|x| async move { x.to_uppercase() })
+        .then_async(|x| async { x + &suffix });
+    let text = " hello ".to_string();
+    let future = pipeline.apply(&text).pipe(assert_send);
+    assert_eq!(block_on(future), "HELLO!");
+}
+
+#[test]
+fn pipe_with() {
+    fn pad(text: &str, width: usize) -> String {
+        format!("{:>width$}", text, width = width)
+    }
+    assert_eq!("ab".pipe_with(2, str::repeat), "abab");
+    assert_eq!("ab".pipe_with(4, pad), "  ab");
+    assert_eq!("ab".pipe_with(6, pad), "    ab");
+    assert_eq!(3.pipe_with(4, |x, y| x * 10 + y), 34);
+}
+
+#[test]
+fn pipe_with2() {
+    fn clamp(x: i32, min: i32, max: i32) -> i32 {
+        x.max(min).min(max)
+    }
+    assert_eq!(12.pipe_with2(0, 10, clamp), 10);
+    assert_eq!((-12).pipe_with2(0, 10, clamp), 0);
+    assert_eq!(1.pipe_with2(2, 3, |x, y, z| (x, y, z)), (1, 2, 3));
+}
+
+#[test]
+fn pipe_flip() {
+    fn sub(x: i32, y: i32) -> i32 {
+        x - y
+    }
+    assert_eq!(3.pipe_flip(10, sub), 7);
+    assert_eq!(3.pipe_with(10, sub), -7);
+    assert!("needle".pipe_flip("haystack with needle", str::contains));
+    assert!(!"pin".pipe_flip("haystack", str::contains));
+}
+
+#[test]
+fn curry_both_sides() {
+    fn sub(x: i32, y: i32) -> i32 {
+        x - y
+    }
+    assert_eq!(3.pipe(curry(sub, 10)), 7);
+    assert_eq!(3.pipe(curry_right(sub, 10)), -7);
+    let greeting = "world".pipe(curry(|a: &str, b: &str| format!("{}, {}!", a, b), "Hello"));
+    assert_eq!(greeting, "Hello, world!");
+}
+
+#[test]
+fn pipe_partial_right() {
+    fn sub(x: i32, y: i32) -> i32 {
+        x - y
+    }
+    assert_eq!(10.pipe_partial_right(3, sub), 10.pipe(|x| sub(x, 3)));
+    assert_eq!(10.pipe(partial_right(sub, 3)), 10.pipe(|x| sub(x, 3)));
+    let suffixed: Vec<_> = ["a", "b"]
+        .iter()
+        .map(|x| x.pipe_partial_right("!", |x: &&str, y| x.to_string() + y))
+        .collect();
+    assert_eq!(suffixed, ["a!", "b!"]);
+}
+
+#[test]
+fn pipe_n() {
+    fn trim(x: String) -> String {
+        x.trim().to_string()
+    }
+    fn lower(x: String) -> String {
+        x.to_lowercase()
+    }
+    fn collapse_whitespace(x: String) -> String {
+        x.split_whitespace().collect::<Vec<_>>().join(" ")
+    }
+    let input = "  Hello   World ".to_string();
+    assert_eq!(input.clone().pipe_n([]), input);
+    assert_eq!(
+        input.pipe_n([trim, lower, collapse_whitespace]),
+        "hello world",
+    );
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn pipe_fold_fns() {
+    use std::boxed::Box;
+    assert_eq!(3.pipe_fold_fns(Vec::new()), 3);
+    let suffix = "!".to_string();
+    let steps: Vec<Box<dyn FnOnce(String) -> String>> = vec![
+        Box::new(|x| x.trim().to_string()),
+        Box::new(|x| x.to_uppercase()),
+        Box::new(move |x| x + &suffix),
+    ];
+    assert_eq!(" hello ".to_string().pipe_fold_fns(steps), "HELLO!");
+}
+
+#[test]
+fn pipe_apply_all() {
+    use std::collections::BTreeMap;
+    let insert = |mut map: BTreeMap<&'static str, i32>, (key, value)| {
+        map.insert(key, value);
+        map
+    };
+    let map = BTreeMap::new().pipe_apply_all(vec![("b", 2), ("a", 1), ("b", 3)], insert);
+    assert_eq!(map.into_iter().collect::<Vec<_>>(), [("a", 1), ("b", 3)]);
+    let unchanged = 12.pipe_apply_all(Vec::<i32>::new(), |_, _| panic!("f should not be called"));
+    assert_eq!(unchanged, 12);
+}
+
+#[test]
+fn pipe_times() {
+    let double = |x: i32| x * 2;
+    assert_eq!(3.pipe_times(0, |_| panic!("f should not be called")), 3);
+    assert_eq!(3.pipe_times(1, double), 6);
+    assert_eq!(3.pipe_times(5, double), 96);
+    let mut calls = 0;
+    let x = 0.pipe_times(5, |x| {
+        calls += 1;
+        x + 1
+    });
+    assert_eq!((x, calls), (5, 5));
+}
+
+#[test]
+fn pipe_while() {
+    let mut steps = Vec::new();
+    let x = 100.pipe_while(
+        |x| *x > 1,
+        |x| {
+            steps.push(x);
+            x / 2
+        },
+    );
+    assert_eq!(x, 1);
+    assert_eq!(steps, [100, 50, 25, 12, 6, 3]);
+    let x = 1.pipe_while(|x| *x > 1, |_| panic!("f should not be called"));
+    assert_eq!(x, 1);
+}
+
+#[test]
+fn pipe_until() {
+    let collatz_floor = |x: &u32| if *x > 1 && x % 2 == 0 { x / 2 } else { *x };
+    assert_eq!(48.pipe_until(collatz_floor), 3);
+    assert_eq!(7.pipe_until(collatz_floor), 7);
+}
+
+#[test]
+fn pipe_until_bounded() {
+    let mut calls = 0;
+    let x = 48.pipe_until_bounded(10, |x: &u32| {
+        calls += 1;
+        if x % 2 == 0 {
+            x / 2
+        } else {
+            *x
+        }
+    });
+    assert_eq!(x, Ok(3));
+    assert_eq!(calls, 5);
+    let x = 0.pipe_until_bounded(3, |x: &i32| x + 1);
+    assert_eq!(x, Err(3));
+    let x = 0.pipe_until_bounded(0, |_: &i32| -> i32 { panic!("f should not be called") });
+    assert_eq!(x, Err(0));
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn pipe_repeat() {
+    let empty = 1.pipe_repeat(0, |_| panic!("f should not be called"));
+    assert!(empty.is_empty());
+    let history = "a".to_string().pipe_repeat(3, |x| x.repeat(2));
+    assert_eq!(history.len(), 3);
+    assert_eq!(history, ["aa", "aaaa", "aaaaaaaa"]);
+}
+
+#[test]
+fn pipe_reduce() {
+    let summary = "third".pipe_reduce(String::from("first, second"), |acc, x| acc + ", " + x);
+    assert_eq!(summary, "first, second, third");
+    let (count, total) = 12.pipe_reduce((2, 30), |(count, total), x| (count + 1, total + x));
+    assert_eq!((count, total), (3, 42));
+}
+
+#[test]
+fn pipe_accumulate() {
+    let words = vec!["hello".to_string()].pipe_accumulate(
+        "a b c".split(' '),
+        String::new(),
+        |acc, x| acc + x,
+        |mut words, joined| {
+            words.push(joined);
+            words
+        },
+    );
+    assert_eq!(words, ["hello", "abc"]);
+    let unchanged = 12.pipe_accumulate(
+        Vec::<i32>::new(),
+        0,
+        |_, _| panic!("fold should not be called"),
+        |x, acc| x + acc,
+    );
+    assert_eq!(unchanged, 12);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn pipe_scan() {
+    let running_totals = 10.pipe_scan(vec![1, 2, 3], |total, x| total + x);
+    assert_eq!(running_totals, [11, 13, 16]);
+    let empty = 10.pipe_scan(Vec::<i32>::new(), |_, _| panic!("f should not be called"));
+    assert!(empty.is_empty());
+    let prefixes = String::new().pipe_scan("abc".chars(), |prefix, x| format!("{}{}", prefix, x));
+    assert_eq!(prefixes, ["a", "ab", "abc"]);
+}
+
+#[test]
+fn pipe_into() {
+    #[derive(Debug, PartialEq, Eq)]
+    struct Meters(u64);
+    impl From<u32> for Meters {
+        fn from(x: u32) -> Self {
+            Meters(x.into())
+        }
+    }
+    assert_eq!(12u8.pipe_into::<u32>(), 12u32);
+    let wide: i64 = (-12i16).pipe_into();
+    assert_eq!(wide, -12);
+    assert_eq!(12u32.pipe_into::<Meters>(), Meters(12));
+    let text: String = "hello".pipe_into();
+    assert_eq!(text, "hello");
+}
+
+#[test]
+fn pipe_try_into() {
+    fn narrow(x: i64) -> Result<u8, core::num::TryFromIntError> {
+        let x = x.pipe_try_into::<u8>()?;
+        Ok(x)
+    }
+    assert_eq!(narrow(255), Ok(255));
+    assert!(narrow(256).is_err());
+    assert!(narrow(-1).is_err());
+    let doubled = 12u64.pipe_try_into().pipe_ok(|x: u16| x * 2);
+    assert_eq!(doubled, Ok(24));
+}
+
+#[test]
+fn pipe_from() {
+    let text = String::pipe_from("hello");
+    assert_eq!(text, "hello");
+    assert_eq!(u64::pipe_from(12u8), 12);
+    assert_eq!(String::pipe_from("hello"), "hello".pipe_into::<String>());
+}
+
+#[test]
+fn pipe_try_from() {
+    let bytes = vec![1u8, 2, 3, 4];
+    let array = <[u8; 4]>::pipe_try_from(bytes.as_slice()).pipe_ok(u32::from_be_bytes);
+    assert_eq!(array.ok(), Some(0x01020304));
+    assert!(<[u8; 3]>::pipe_try_from(bytes.as_slice()).is_err());
+    assert_eq!(u8::pipe_try_from(255i32), Ok(255));
+    assert!(u8::pipe_try_from(256i32).is_err());
+}
+
+#[test]
+fn pipe_as_ref_turbofish() {
+    use std::{ffi::OsStr, path::Path};
+    let text = "src/lib.rs".to_string();
+    assert_eq!(text.pipe_as_ref::<str, _, _>(str::len), 10);
+    assert_eq!(
+        text.pipe_as_ref::<[u8], _, _>(|x| x.first().copied()),
+        Some(b's')
+    );
+    assert_eq!(
+        text.pipe_as_ref::<Path, _, _>(Path::extension),
+        Some(OsStr::new("rs"))
+    );
+    assert_eq!(text.pipe_as_ref::<OsStr, _, _>(OsStr::len), 10);
+}
+
+#[test]
+fn pipe_as_mut_turbofish() {
+    let mut vec = vec![3, 1, 2];
+    vec.pipe_as_mut::<[i32], _, _>(<[i32]>::sort_unstable);
+    assert_eq!(vec, [1, 2, 3]);
+    vec.pipe_as_mut::<Vec<i32>, _, _>(|x| x.push(4));
+    assert_eq!(vec, [1, 2, 3, 4]);
+}
+
+#[test]
+fn pipe_convert() {
+    assert_eq!("12".pipe_convert(str::len), "12".pipe(str::len));
+    let x = 255u8.pipe_convert_into::<u32, u64>();
+    assert_eq!(x, 255u64);
+    #[derive(Debug, PartialEq, Eq)]
+    struct Id(u64);
+    impl From<u32> for Id {
+        fn from(x: u32) -> Self {
+            Id(x.into())
+        }
+    }
+    assert_eq!(12u8.pipe_convert_into::<u32, Id>(), Id(12));
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn pipe_to_string() {
+    use core::fmt;
+    struct Point(i32, i32);
+    impl fmt::Display for Point {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "({}, {})", self.0, self.1)
+        }
+    }
+    assert_eq!(12.pipe_to_string(), "12");
+    assert_eq!((-1.5).pipe_to_string(), "-1.5");
+    assert_eq!(Point(1, 2).pipe_to_string(), "(1, 2)");
+}
+
+#[test]
+fn pipe_parse() {
+    let valid = "42".to_string();
+    assert_eq!(valid.pipe_parse::<i32>(), Ok(42));
+    assert_eq!(valid.pipe_parse::<u8>(), Ok(42));
+    let invalid = "forty-two".to_string();
+    assert!(invalid.pipe_parse::<i32>().is_err());
+    assert!("300".pipe_parse::<u8>().is_err());
+}
+
+#[test]
+fn pipe_as_bytes() {
+    let text = "hi".to_string();
+    assert_eq!(text.pipe_as_bytes(<[u8]>::to_vec), [b'h', b'i']);
+    assert_eq!("hi".pipe_as_bytes(<[u8]>::len), 2);
+    let bytes: Vec<u8> = vec![1, 2, 3];
+    assert_eq!(
+        bytes.pipe_as_bytes(|x| x.iter().rev().copied().collect::<Vec<_>>()),
+        [3, 2, 1]
+    );
+}
+
+#[test]
+fn pipe_macro_turbofish() {
+    let words = pipe!("b a" => .split(' ') => .rev() => .collect::<Vec<_>>());
+    assert_eq!(words, ["a", "b"]);
+    let total = pipe_let!("1 2 3" => .split(' ') => .map(str::parse::<i32>) => .collect::<Result<Vec<_>, _>>());
+    assert_eq!(total, Ok(vec![1, 2, 3]));
+    fn parse(text: &str) -> Result<u8, core::num::ParseIntError> {
+        Ok(try_pipe!(text => .parse::<u8>()))
+    }
+    assert_eq!(parse("12"), Ok(12));
+    assert!(parse("300").is_err());
+}